@@ -0,0 +1,60 @@
+// 把构建配置写成常量，供结果 JSON 的 build 段与「关于」页使用。
+// 调试构建跑出来的数字没有参考意义，界面据此给出提示
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn var(name: &str) -> String {
+    env::var(name).unwrap_or_default()
+}
+
+// Cargo 不向构建脚本传 lto 设置：依次看环境变量覆盖、RUSTFLAGS 与 Cargo.toml 的 [profile.*]，都没有时为 Cargo 默认的 false
+fn lto(profile: &str) -> String {
+    // PROFILE 只会是 debug 或 release，前者对应 [profile.dev]
+    let profile = if profile == "debug" { "dev" } else { profile };
+    if let Ok(value) = env::var(format!("CARGO_PROFILE_{}_LTO", profile.to_uppercase())) {
+        return value;
+    }
+    let flags = var("CARGO_ENCODED_RUSTFLAGS");
+    if let Some(flag) = flags
+        .split('\x1f')
+        .find_map(|flag| flag.strip_prefix("-Clto"))
+    {
+        return flag.trim_start_matches('=').to_string();
+    }
+    let manifest = Path::new(&var("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let text = fs::read_to_string(manifest).unwrap_or_default();
+    let header = format!("[profile.{}]", profile);
+    let mut in_section = false;
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_section = line == header;
+        } else if in_section
+            && let Some((key, value)) = line.split_once('=')
+            && key.trim() == "lto"
+        {
+            return value.trim().trim_matches('"').to_string();
+        }
+    }
+    "false".to_string()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    let profile = var("PROFILE");
+    let content = format!(
+        "pub const PROFILE: &str = {:?};\n\
+         pub const OPT_LEVEL: &str = {:?};\n\
+         pub const LTO: &str = {:?};\n\
+         pub const TARGET: &str = {:?};\n\
+         pub const TARGET_FEATURES: &str = {:?};\n",
+        profile,
+        var("OPT_LEVEL"),
+        lto(&profile),
+        var("TARGET"),
+        var("CARGO_CFG_TARGET_FEATURE"),
+    );
+    let out = Path::new(&var("OUT_DIR")).join("build_info.rs");
+    fs::write(out, content).expect("failed to write build_info.rs");
+}
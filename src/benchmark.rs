@@ -1,24 +1,491 @@
-use std::time::Instant;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use serde_json::{Value, json};
+
+use crate::{build_info, timestamp};
+
+mod ab;
+mod cases;
+mod conditions;
+mod conformance;
+mod custom;
+mod device;
+mod guardrail;
+mod prng;
+mod progress;
+mod stats;
+mod sustained;
+mod thresholds;
+mod timing;
+mod trace;
+
+pub use ab::{AB_PASSES, AbResult, AbRow, AbVariant, run_ab};
+pub use cases::{CASES, CaseDef, all as all_cases, find as find_case};
+use cases::{CaseRun, OnChunk, Workspace};
+use conditions::delta_json;
+pub use conditions::{Conditions, ConditionsDelta, LOOP_CHANGE_LIMIT, UNAVAILABLE};
+pub use conformance::{EntryCheck, FIXTURE_VERSION, check_fixture, generate_fixture};
+pub use custom::{register as register_case, registry_json, unregister as unregister_case};
+pub use device::{ClassDefaults, DeviceClass};
+pub use progress::{PlannedCase, ProgressMeter, ProgressSplit, step_plan};
+use stats::calc_stats;
+pub use stats::{BenchStats, Welford, median, p95, quantile, summarize};
+pub use sustained::{
+    MINUTES_CHOICES, SAMPLE_SECS, SUSTAINED_KERNEL, SustainedResult, SustainedSample, run_sustained,
+};
+pub use thresholds::{CaseThreshold, Verdict};
+use thresholds::{evaluate, thresholds_json};
+pub use timing::{NoiseProbe, TimerProbe, measured_timer_probe, noise_probe, timer_probe};
+pub use trace::{ChunkSpan, chrome_trace};
 
 pub const BENCH_SEED: u32 = 12345;
 pub const BENCH_N1: u64 = 300_000_000;
 pub const BENCH_N2: u64 = 200_000_000;
 pub const BENCH_WARMUP: usize = 3;
 pub const BENCH_REPEATS: usize = 9;
-pub const TOTAL_STEPS: usize = 2 * (BENCH_WARMUP + BENCH_REPEATS);
+pub const BENCH_MEM_BYTES: usize = 8 * 1024 * 1024;
+pub const BENCH_MEM_PASSES: u64 = 16;
+pub const CASE_COUNT: usize = CASES.len();
+// 单次迭代默认最多切成多少块上报进度，块之间的回调耗时不计入测量
+pub const MAX_CHUNKS: usize = 32;
+// 正式迭代耗时超过已有样本中位数 RETRY_FACTOR 倍时判为噪声重跑，每个用例至多重跑 RETRY_BUDGET 次
+pub const RETRY_FACTOR: f64 = 3.0;
+pub const RETRY_BUDGET: usize = 3;
+// 样本太少时中位数本身不可靠，不做判定
+const RETRY_MIN_SAMPLES: usize = 3;
+// 设置里可选的块数：渲染慢的宿主选少块，想要平滑进度的选多块
+pub const CHUNK_CHOICES: [usize; 4] = [4, 16, MAX_CHUNKS, 128];
+// 滚动速度估计取最近多少块
+pub const RATE_WINDOW: usize = 8;
+// 进度事件流的节流间隔。合并块回调时按它换算步长，使回调大致每隔这么久一次
+pub const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+const PAUSE_POLL: Duration = Duration::from_millis(50);
+
+// 卸载对比所用的宿主接口。psys_host 目前没有计算、画布或 GPU 接口，
+// 卸载用例无从实现，结果中只标记为不可用；宿主提供后在这里接入
+pub const OFFLOAD_API: Option<&str> = None;
+// 读取电量所用的宿主接口。psys_host 没有电池接口，无法按长时间运行前后的电量下降
+// 估算每百万次操作的能耗（mJ/Mop），结果中只标记为不可用；宿主提供后在这里接入
+pub const BATTERY_API: Option<&str> = None;
+
+static CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+static PAUSE_REQUESTED: AtomicBool = AtomicBool::new(false);
+// 同一时刻只允许一次运行，见 try_start
+static RUN_ACTIVE: AtomicBool = AtomicBool::new(false);
+// 本进程内已经执行过的用例，用来判断冷启动那一次是否真的是首次执行
+static EXECUTED_CASES: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+// digest 出口（内存用例还有每一遍）的防优化屏障。部分宿主工具链对 black_box 的处理不同，
+// 切换到 volatile 读可以佐证内核没有被常量折叠掉
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Barrier {
+    BlackBox,
+    Volatile,
+}
+
+impl Barrier {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Barrier::BlackBox => "black_box",
+            Barrier::Volatile => "volatile",
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            Barrier::BlackBox => Barrier::Volatile,
+            Barrier::Volatile => Barrier::BlackBox,
+        }
+    }
+
+    #[inline(always)]
+    fn sink<T: Copy>(self, value: T) -> T {
+        match self {
+            Barrier::BlackBox => std::hint::black_box(value),
+            Barrier::Volatile => {
+                let slot = value;
+                // SAFETY: slot 是已初始化的栈上局部变量，读取期间一直存活
+                unsafe { std::ptr::read_volatile(&slot) }
+            }
+        }
+    }
+
+    // 每遍写完后交出缓冲区，各遍的写入都不能被合并或删掉。volatile 模式用编译器栅栏，
+    // 不经 black_box
+    #[inline(always)]
+    fn publish<T: ?Sized>(self, value: &mut T) {
+        match self {
+            Barrier::BlackBox => {
+                std::hint::black_box(value);
+            }
+            Barrier::Volatile => std::sync::atomic::compiler_fence(Ordering::SeqCst),
+        }
+    }
+}
+
+// 套件对宿主的全部依赖。benchmark 模块不引用 psys_host，插件和原生参考实现
+// 各自实现这个 trait，跑的是同一份内核，digest 因此可以逐位对照
+pub trait Host {
+    // 在 target 上渲染一棵含 size 个文本元素的树，round 让每次的内容不同
+    fn render_tree(&self, target: &str, size: u32, round: u64);
+    // 结果 JSON 的 env 段
+    fn env_json(&self) -> Value;
+}
+
+// 没有宿主时使用（原生单元测试、命令行）。需要渲染的用例会因缺少渲染目标而跳过
+pub struct NoHost;
+
+impl Host for NoHost {
+    fn render_tree(&self, _target: &str, _size: u32, _round: u64) {}
+
+    fn env_json(&self) -> Value {
+        Value::Null
+    }
+}
+
+// 标准：整套用例各跑一遍；持续：只跑 T1 内核若干分钟，观察降频；
+// A/B：当前配置与只改一个变量的配置交替运行，见 ab
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    Standard,
+    Sustained,
+    Ab,
+}
+
+impl Profile {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Profile::Standard => "standard",
+            Profile::Sustained => "sustained",
+            Profile::Ab => "ab",
+        }
+    }
+}
+
+// 结果 JSON 里逐用例耗时的单位。字段名随单位变化（time_ms / time_us），块内另带 unit
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Ms,
+    Us,
+}
+
+impl TimeUnit {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TimeUnit::Ms => "ms",
+            TimeUnit::Us => "us",
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            TimeUnit::Ms => TimeUnit::Us,
+            TimeUnit::Us => TimeUnit::Ms,
+        }
+    }
+
+    pub fn key(self) -> &'static str {
+        match self {
+            TimeUnit::Ms => "time_ms",
+            TimeUnit::Us => "time_us",
+        }
+    }
+
+    fn convert_ms(self, ms: f64) -> f64 {
+        match self {
+            TimeUnit::Ms => ms,
+            TimeUnit::Us => ms * 1000.0,
+        }
+    }
+}
+
+// 设置里可选的耗时小数位；高分辨率计时的宿主用 6 位才不丢信息
+pub const TIME_DECIMALS_CHOICES: [u32; 2] = [3, 6];
+
+// 某个用例出错、超时或被取消后，其余用例如何处理。取消总是终止整个套件
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FailurePolicy {
+    ContinueOthers,
+    AbortSuite,
+}
+
+impl FailurePolicy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FailurePolicy::ContinueOthers => "continue_others",
+            FailurePolicy::AbortSuite => "abort_suite",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct BenchmarkConfig {
+    pub barrier: Barrier,
+    // 与各用例登记的参考值比对每个用例的 digest
+    pub verify_digests: bool,
+    // 校验开启时，digest 一旦不符立即判定用例失败，不再产出错误内核的耗时
+    pub abort_on_mismatch: bool,
+    pub failure_policy: FailurePolicy,
+    // 单个用例（含预热）的最长耗时，None 表示不限
+    pub case_timeout_ms: Option<u64>,
+    // 在预热前单独计时一次冷启动迭代，并给出冷/热耗时比
+    pub cold_start: bool,
+    // 宿主 UI 用例渲染到的元素 id，由界面在开始测试时填入
+    pub render_target: Option<String>,
+    pub chunks: usize,
+    // 进度同时以 JSON 事件转发给宿主，见 progress_stream
+    pub stream_progress: bool,
+    pub profile: Profile,
+    // 持续负载的运行时长，只在 Profile::Sustained 下生效
+    pub sustained_minutes: u32,
+    // 持续负载的心跳除写日志外也追加到数据目录，见 heartbeat
+    pub heartbeat_log: bool,
+    // 从较短的块耗时中扣除启动时标定的 Instant::now() 开销
+    pub timer_correction: bool,
+    // warmup / repeats / skip_cases 的默认值随设备类别变化，见 for_class
+    pub device_class: DeviceClass,
+    pub warmup: usize,
+    pub repeats: usize,
+    pub skip_cases: Vec<&'static str>,
+    // 正式迭代超过已有样本中位数 RETRY_FACTOR 倍时丢弃重跑，每个用例最多 RETRY_BUDGET 次
+    pub retry_noisy: bool,
+    // 验收阈值，只包含设定过的用例
+    pub thresholds: Vec<CaseThreshold>,
+    // A/B 模式下 B 相对 A 改动的变量
+    pub ab_variant: AbVariant,
+    // 记录逐块耗时，跑完后以 Chrome trace 格式写入插件数据目录
+    pub export_trace: bool,
+    // 块很短的设备上每块一次回调本身也可测，开启后按 PROGRESS_INTERVAL 每隔若干块才回调一次
+    pub batch_chunks: bool,
+    // 结果 JSON 中逐用例耗时（time 块与冷启动耗时）的单位与小数位
+    pub time_unit: TimeUnit,
+    pub time_decimals: u32,
+    // 高级设置：单个用例的迭代数，已按 n_override_range 限定，只作用于标准测试。
+    // 规模变了参考 digest 不再成立，覆盖过的用例不做校验
+    pub n_overrides: Vec<(&'static str, u64)>,
+}
+
+impl BenchmarkConfig {
+    pub fn threshold(&self, id: &str) -> Option<&CaseThreshold> {
+        self.thresholds.iter().find(|t| t.id == id && t.is_set())
+    }
+
+    pub fn n_override(&self, id: &str) -> Option<u64> {
+        self.n_overrides
+            .iter()
+            .find(|(case, _)| *case == id)
+            .map(|&(_, n)| n)
+    }
+
+    // 实际运行的 n1/n2，T1/T2 覆盖过迭代数时为覆盖值
+    pub fn n1(&self) -> u64 {
+        self.n_override("T1_INT32_MIX").unwrap_or(BENCH_N1)
+    }
+
+    pub fn n2(&self) -> u64 {
+        self.n_override("T2_FP64_DOT").unwrap_or(BENCH_N2)
+    }
+}
+
+// 迭代数覆盖的范围：默认 n 的 1/N_OVERRIDE_MIN_DIVISOR 到 N_OVERRIDE_MAX_FACTOR 倍
+pub const N_OVERRIDE_MIN_DIVISOR: u64 = 100;
+pub const N_OVERRIDE_MAX_FACTOR: u64 = 4;
+
+pub fn n_override_range(case: &CaseDef) -> (u64, u64) {
+    (
+        (case.n / N_OVERRIDE_MIN_DIVISOR).max(1),
+        case.n.saturating_mul(N_OVERRIDE_MAX_FACTOR),
+    )
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            barrier: Barrier::BlackBox,
+            verify_digests: true,
+            abort_on_mismatch: false,
+            failure_policy: FailurePolicy::ContinueOthers,
+            case_timeout_ms: None,
+            cold_start: false,
+            render_target: None,
+            chunks: MAX_CHUNKS,
+            stream_progress: false,
+            profile: Profile::Standard,
+            sustained_minutes: 5,
+            heartbeat_log: false,
+            timer_correction: true,
+            device_class: DeviceClass::Unknown,
+            warmup: BENCH_WARMUP,
+            repeats: BENCH_REPEATS,
+            skip_cases: Vec::new(),
+            retry_noisy: false,
+            thresholds: Vec::new(),
+            ab_variant: AbVariant::Chunks,
+            export_trace: false,
+            batch_chunks: false,
+            time_unit: TimeUnit::Ms,
+            time_decimals: TIME_DECIMALS_CHOICES[0],
+            n_overrides: Vec::new(),
+        }
+    }
+}
+
+impl BenchmarkConfig {
+    pub fn for_class(class: DeviceClass) -> Self {
+        let defaults = class.defaults();
+        Self {
+            device_class: class,
+            warmup: defaults.warmup,
+            repeats: defaults.repeats,
+            skip_cases: defaults.skip_cases.to_vec(),
+            ..Self::default()
+        }
+    }
+}
+
+pub enum BenchError {
+    DigestMismatch {
+        id: &'static str,
+        expected: u64,
+        actual: u64,
+    },
+    TimedOut {
+        id: &'static str,
+        limit_ms: u64,
+    },
+    Cancelled {
+        id: &'static str,
+    },
+    // 内核自身报告的错误，如宿主 I/O 失败
+    Kernel {
+        id: &'static str,
+        message: String,
+    },
+}
+
+impl fmt::Display for BenchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BenchError::DigestMismatch {
+                id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} digest 不符: 期望 {:016x}, 实际 {:016x}",
+                id, expected, actual
+            ),
+            BenchError::TimedOut { id, limit_ms } => {
+                write!(f, "{} 超时: 超过 {} ms", id, limit_ms)
+            }
+            BenchError::Cancelled { id } => write!(f, "{} 已取消", id),
+            BenchError::Kernel { id, message } => write!(f, "{} 出错: {}", id, message),
+        }
+    }
+}
+
+// 在迭代之间生效，正在执行的迭代会先跑完
+pub fn request_cancel() {
+    CANCEL_REQUESTED.store(true, Ordering::Relaxed);
+}
+
+// 上一次运行是否被取消，下一次运行开始时清除
+pub fn cancel_requested() -> bool {
+    CANCEL_REQUESTED.load(Ordering::Relaxed)
+}
+
+// 与取消一样在迭代之间生效，暂停期间不计入耗时与用例超时
+pub fn request_pause() {
+    PAUSE_REQUESTED.store(true, Ordering::Relaxed);
+}
+
+pub fn resume() {
+    PAUSE_REQUESTED.store(false, Ordering::Relaxed);
+}
+
+// 每次运行开始时清除上一次留下的取消与暂停请求
+fn clear_requests() {
+    CANCEL_REQUESTED.store(false, Ordering::Relaxed);
+    PAUSE_REQUESTED.store(false, Ordering::Relaxed);
+}
+
+// 暂停时每隔 PAUSE_POLL 检查一次继续或取消，返回暂停了多久
+fn wait_while_paused() -> Duration {
+    let started = Instant::now();
+    while PAUSE_REQUESTED.load(Ordering::Relaxed) && !CANCEL_REQUESTED.load(Ordering::Relaxed) {
+        std::thread::sleep(PAUSE_POLL);
+    }
+    started.elapsed()
+}
+
+// 开跑的闸门：界面点击与宿主命令都先经 compare_exchange 占住，抢不到的返回 None，
+// 保证两个几乎同时到达的开始请求只有一个真正开跑。RunSlot 释放时归还
+pub struct RunSlot(());
+
+impl Drop for RunSlot {
+    fn drop(&mut self) {
+        RUN_ACTIVE.store(false, Ordering::Release);
+    }
+}
+
+pub fn try_start() -> Option<RunSlot> {
+    RUN_ACTIVE
+        .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+        .ok()
+        .map(|_| RunSlot(()))
+}
+
+pub fn run_active() -> bool {
+    RUN_ACTIVE.load(Ordering::Acquire)
+}
+
+pub fn total_steps(config: &BenchmarkConfig) -> usize {
+    step_plan(config).iter().map(|case| case.steps).sum()
+}
+
+pub fn reference_digest(id: &str) -> Option<u64> {
+    find_case(id).and_then(|case| case.reference)
+}
 
 #[derive(Clone, Copy)]
 pub enum BenchPhase {
+    Cold,
     Warmup,
     Measure,
 }
 
+impl BenchPhase {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BenchPhase::Cold => "cold",
+            BenchPhase::Warmup => "warmup",
+            BenchPhase::Measure => "measure",
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum BenchStepStatus {
     Started,
+    Running,
     Finished,
 }
 
+impl BenchStepStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BenchStepStatus::Started => "started",
+            BenchStepStatus::Running => "running",
+            BenchStepStatus::Finished => "finished",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
 pub struct ProgressUpdate {
     pub bench_id: &'static str,
     pub phase: BenchPhase,
@@ -27,266 +494,1018 @@ pub struct ProgressUpdate {
     pub completed_steps: usize,
     pub total_steps: usize,
     pub status: BenchStepStatus,
+    pub chunk_index: usize,
+    pub chunk_total: usize,
+    // 当前迭代已累计的内核耗时
+    pub elapsed_ms: f64,
+    // 最近 RATE_WINDOW 块的滚动吞吐 (ops/s)
+    pub ops_per_sec: Option<f64>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CaseOutcome {
+    Ok,
+    Failed,
+    Skipped,
+    Cancelled,
 }
 
-pub struct BenchStats {
-    pub min: f64,
-    pub p50: f64,
-    pub p95: f64,
-    pub max: f64,
+impl CaseOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CaseOutcome::Ok => "ok",
+            CaseOutcome::Failed => "failed",
+            CaseOutcome::Skipped => "skipped",
+            CaseOutcome::Cancelled => "cancelled",
+        }
+    }
 }
 
+pub struct ColdPass {
+    pub time_ms: f64,
+    // 冷启动迭代是否是该用例在本进程内的第一次执行
+    pub first_in_process: bool,
+    // 冷启动耗时 / 热身后的 p50
+    pub ratio: f64,
+}
+
+pub struct Relative {
+    pub baseline: &'static str,
+    // 本用例 p50 / 对照用例 p50，大于 1 表示更慢
+    pub ratio: f64,
+    // 没有设定阈值时为 None
+    pub flagged: Option<bool>,
+}
+
+pub struct Throughput {
+    pub value: f64,
+    pub unit: &'static str,
+}
+
+// 只有 outcome 为 Ok 时 digest/stats/throughput 才有意义
 pub struct BenchCaseResult {
     pub id: &'static str,
+    pub outcome: CaseOutcome,
+    pub error: Option<String>,
+    // 计划内跳过（设备类别、缺渲染目标、护栏）的原因；因整套中止而没跑时为 None
+    pub skip_reason: Option<String>,
     pub digest: u64,
+    // 未开启校验或没有参考值时为 None
+    pub digest_ok: Option<bool>,
     pub stats: BenchStats,
+    pub throughput: Option<Throughput>,
+    pub cold: Option<ColdPass>,
+    pub relative: Option<Relative>,
+    pub retries: Vec<Retry>,
+    // 该用例设定了验收阈值时才有
+    pub verdict: Option<Verdict>,
+    // 实际使用的块回调步长，未合并时为 1
+    pub chunk_stride: usize,
 }
 
-pub struct BenchmarkResult {
-    pub t1: BenchCaseResult,
-    pub t2: BenchCaseResult,
-    pub final_digest: u64,
-    pub json: String,
+// 被丢弃并重跑的一次正式迭代
+pub struct Retry {
+    // 第几次正式迭代（从 1 开始）
+    pub index: usize,
+    pub discarded_ms: f64,
+    // 判定时已有样本的中位数
+    pub median_ms: f64,
 }
 
-fn median(sorted: &[f64]) -> f64 {
-    let n = sorted.len();
-    if n == 0 {
-        return f64::NAN;
-    }
-    if n % 2 == 1 {
-        sorted[n / 2]
-    } else {
-        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
-    }
+pub struct BenchmarkResult {
+    pub cases: Vec<BenchCaseResult>,
+    pub policy: FailurePolicy,
+    // 开跑前测得的空载噪声
+    pub noise: NoiseProbe,
+    pub conditions_before: Conditions,
+    // 跑完后再测一次；中途取消时为 None
+    pub conditions_after: Option<Conditions>,
+    // 旧的 T1 ^ T2，过渡期内与 suite_digest 并存；T1 或 T2 覆盖过迭代数时无法与参考值对照，为 None
+    pub final_digest: Option<u64>,
+    pub suite_digest: u64,
+    pub started_unix_ms: u64,
+    pub json: String,
+    // 只在 export_trace 开启时有内容
+    pub trace: Vec<ChunkSpan>,
 }
 
-fn p95(sorted: &[f64]) -> f64 {
-    let n = sorted.len();
-    if n == 0 {
-        return f64::NAN;
-    }
-    let idx = ((n as f64 - 1.0) * 0.95).round() as usize;
-    sorted[idx.min(n - 1)]
+struct StepCounter {
+    completed: usize,
+    total: usize,
 }
 
-// -------- xorshift32 PRNG (pure 32-bit, cross-lang) --------
-struct XorShift32 {
-    x: u32,
+struct RateWindow {
+    samples: VecDeque<(f64, f64)>,
 }
 
-impl XorShift32 {
-    fn new(seed: u32) -> Self {
-        let x = if seed == 0 { 0x6D2B79F5 } else { seed };
-        Self { x }
+impl RateWindow {
+    fn new() -> Self {
+        Self {
+            samples: VecDeque::with_capacity(RATE_WINDOW),
+        }
     }
 
-    #[inline]
-    fn next_u32(&mut self) -> u32 {
-        let mut x = self.x;
-        x ^= x << 13;
-        x ^= x >> 17;
-        x ^= x << 5;
-        self.x = x;
-        x
+    fn push(&mut self, ops: f64, secs: f64) {
+        if self.samples.len() == RATE_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((ops, secs));
     }
 
-    #[inline]
-    fn next_f64_01(&mut self) -> f64 {
-        (self.next_u32() as f64) / 4294967296.0 // 2^32
+    fn ops_per_sec(&self) -> Option<f64> {
+        let (ops, secs) = self
+            .samples
+            .iter()
+            .fold((0.0, 0.0), |(o, s), &(ops, secs)| (o + ops, s + secs));
+        (secs > 0.0).then(|| ops / secs)
     }
 }
 
-// -------- Benchmarks --------
-#[inline(never)]
-fn bench_int32_mix(seed: u32, n: u64) -> u32 {
-    let mut rng = XorShift32::new(seed);
-    let mut acc: u32 = 0x1234_5678;
+struct RunGuard {
+    abort_unless: Option<u64>,
+    timeout: Option<Duration>,
+    // 从较短的块耗时中扣除的计时开销，关闭校正时为零
+    timer_overhead: Duration,
+    // 关闭噪声重跑时为 0
+    retry_budget: usize,
+    // 导出 trace 时为本次运行的起点，逐块记录相对它的时间
+    trace_origin: Option<Instant>,
+    batch_chunks: bool,
+}
 
-    for i in 0..n {
-        let x = rng.next_u32();
-        let mut v = x ^ acc;
-        v = v.rotate_left((i as u32) & 31);
-        v = v.wrapping_mul(0x9E37_79B1);
-        v ^= v >> 16;
-        acc = acc.wrapping_add(v);
-        if (v & 0x8000) != 0 {
-            acc ^= 0xA5A5_A5A5;
+impl RunGuard {
+    // reference 为已按 verify_digests 过滤的参考 digest
+    fn for_config(config: &BenchmarkConfig, reference: Option<u64>) -> Self {
+        RunGuard {
+            abort_unless: reference.filter(|_| config.abort_on_mismatch),
+            timeout: config.case_timeout_ms.map(Duration::from_millis),
+            timer_overhead: timer_overhead(config),
+            retry_budget: if config.retry_noisy { RETRY_BUDGET } else { 0 },
+            trace_origin: None,
+            batch_chunks: config.batch_chunks,
         }
     }
-
-    std::hint::black_box(acc)
 }
 
-#[inline(never)]
-fn bench_fp64_dot(seed: u32, n: u64) -> u64 {
-    let mut rng = XorShift32::new(seed ^ 0xDEAD_BEEF);
-    let mut sum: f64 = 0.0;
-    let c: f64 = 1e-9;
+// 超出已有正式样本中位数 RETRY_FACTOR 倍时返回该中位数
+fn noisy_iteration(times: &[f64], elapsed_ms: f64) -> Option<f64> {
+    if times.len() < RETRY_MIN_SAMPLES {
+        return None;
+    }
+    let mut sorted = times.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let median = median(&sorted);
+    (elapsed_ms > median * RETRY_FACTOR).then_some(median)
+}
 
-    for _ in 0..n {
-        let a = rng.next_f64_01();
-        let b = rng.next_f64_01();
-        sum = sum + (a * b + c);
+fn timer_overhead(config: &BenchmarkConfig) -> Duration {
+    if config.timer_correction {
+        timer_probe().chunk_overhead()
+    } else {
+        Duration::ZERO
     }
+}
 
-    std::hint::black_box(sum.to_bits())
+struct RawTimes {
+    digest: u64,
+    times: Vec<f64>,
+    cold_ms: Option<f64>,
+    retries: Vec<Retry>,
+    spans: Vec<ChunkSpan>,
+    chunk_stride: usize,
+}
+
+// 一次迭代分三步调用：Reset 在计时前恢复被原地改写的输入，Execute 运行内核并计时，
+// Finalize 在计时结束后由 Execute 的返回值算出 digest
+enum Stage {
+    Reset,
+    Execute { stride: usize },
+    Finalize(u64),
 }
 
 fn run_bench<F, P>(
-    name: &'static str,
-    warmup: usize,
-    repeats: usize,
+    spec: &CaseDef,
+    plan: &[(BenchPhase, usize)],
+    guard: &RunGuard,
     mut f: F,
     progress: &mut P,
-    completed_steps: &mut usize,
-    total_steps: usize,
-) -> (u64, Vec<f64>)
+    steps: &mut StepCounter,
+) -> Result<RawTimes, BenchError>
 where
-    F: FnMut() -> u64,
+    F: FnMut(Stage, &mut OnChunk) -> Result<u64, String>,
     P: FnMut(ProgressUpdate),
 {
+    let name = spec.id;
+    // 合并块回调时由第一次迭代的块耗时定下步长，此后各次迭代不变，正式迭代之间可比
+    let mut stride = 1;
+    let mut stride_fixed = !guard.batch_chunks;
     let mut last = 0u64;
-    for i in 0..warmup {
-        progress(ProgressUpdate {
+    let mut times: Vec<f64> = Vec::new();
+    let mut cold_ms = None;
+    let mut retries = Vec::new();
+    let mut spans = Vec::new();
+    let mut rate = RateWindow::new();
+    let mut iterations = plan
+        .iter()
+        .flat_map(|&(phase, total)| (1..=total).map(move |index| (phase, index, total)));
+    let mut started = Instant::now();
+
+    // 被判为噪声的迭代不前进 current，直接重跑同一个 index
+    let mut current = iterations.next();
+    while let Some((phase, index, total)) = current {
+        started += wait_while_paused();
+        if CANCEL_REQUESTED.load(Ordering::Relaxed) {
+            return Err(BenchError::Cancelled { id: name });
+        }
+
+        let base = ProgressUpdate {
             bench_id: name,
-            phase: BenchPhase::Warmup,
-            index: i + 1,
-            total: warmup,
-            completed_steps: *completed_steps,
-            total_steps,
+            phase,
+            index,
+            total,
+            completed_steps: steps.completed,
+            total_steps: steps.total,
             status: BenchStepStatus::Started,
-        });
-        last = f();
-        *completed_steps += 1;
+            chunk_index: 0,
+            chunk_total: 0,
+            elapsed_ms: 0.0,
+            ops_per_sec: rate.ops_per_sec(),
+        };
+        progress(base);
+
+        let reset = f(Stage::Reset, &mut |_, _| {});
+        let mut elapsed = Duration::ZERO;
+        let mut mark = Instant::now();
+        let correct_below = guard.timer_overhead.mul_f64(TimerProbe::CORRECT_BELOW);
+        let mut chunks_seen = 0;
+        let mut chunk_count = 0;
+        let raw = f(
+            Stage::Execute { stride },
+            &mut |chunk_index, chunk_total| {
+                let mut chunk_time = mark.elapsed();
+                if chunk_time < correct_below {
+                    chunk_time = chunk_time.saturating_sub(guard.timer_overhead);
+                }
+                elapsed += chunk_time;
+                if let Some(origin) = guard.trace_origin {
+                    spans.push(ChunkSpan {
+                        case: name,
+                        phase,
+                        iteration: index,
+                        chunk: chunk_index,
+                        start_us: (mark - origin).as_secs_f64() * 1e6,
+                        dur_us: chunk_time.as_secs_f64() * 1e6,
+                    });
+                }
+                // 一次回调可能覆盖多块
+                let covered = chunk_index - chunks_seen;
+                chunks_seen = chunk_index;
+                chunk_count = chunk_total;
+                rate.push(
+                    (spec.n * spec.ops_per_n) as f64 * covered as f64 / chunk_total as f64,
+                    chunk_time.as_secs_f64(),
+                );
+                progress(ProgressUpdate {
+                    status: BenchStepStatus::Running,
+                    chunk_index,
+                    chunk_total,
+                    elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+                    ops_per_sec: rate.ops_per_sec(),
+                    ..base
+                });
+                mark = Instant::now();
+            },
+        );
+        elapsed += mark.elapsed();
+        if !stride_fixed && chunk_count > 0 {
+            stride_fixed = true;
+            let per_chunk = elapsed.as_secs_f64() / chunk_count as f64;
+            if per_chunk > 0.0 {
+                let fit = (PROGRESS_INTERVAL.as_secs_f64() / per_chunk) as usize;
+                stride = fit.clamp(1, chunk_count);
+            }
+        }
+        let digest = reset
+            .and(raw)
+            .and_then(|raw| f(Stage::Finalize(raw), &mut |_, _| {}));
+        last = match digest {
+            Ok(digest) => digest,
+            Err(message) => {
+                tracing::error!("{} failed: {}", name, message);
+                return Err(BenchError::Kernel { id: name, message });
+            }
+        };
+
+        if let Some(expected) = guard.abort_unless
+            && last != expected
+        {
+            tracing::error!(
+                "{} digest mismatch: {:016x} != {:016x}",
+                name,
+                last,
+                expected
+            );
+            return Err(BenchError::DigestMismatch {
+                id: name,
+                expected,
+                actual: last,
+            });
+        }
+
+        if let Some(limit) = guard.timeout
+            && started.elapsed() > limit
+        {
+            tracing::error!("{} timed out after {:?}", name, started.elapsed());
+            return Err(BenchError::TimedOut {
+                id: name,
+                limit_ms: limit.as_millis() as u64,
+            });
+        }
+
+        let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+        if matches!(phase, BenchPhase::Measure)
+            && retries.len() < guard.retry_budget
+            && let Some(median_ms) = noisy_iteration(&times, elapsed_ms)
+        {
+            tracing::warn!(
+                "{} iteration {} took {:.3} ms (median {:.3} ms), retrying",
+                name,
+                index,
+                elapsed_ms,
+                median_ms
+            );
+            retries.push(Retry {
+                index,
+                discarded_ms: elapsed_ms,
+                median_ms,
+            });
+            continue;
+        }
+        match phase {
+            BenchPhase::Cold => cold_ms = Some(elapsed_ms),
+            BenchPhase::Warmup => {}
+            BenchPhase::Measure => times.push(elapsed_ms),
+        }
+        steps.completed += 1;
         progress(ProgressUpdate {
-            bench_id: name,
-            phase: BenchPhase::Warmup,
-            index: i + 1,
-            total: warmup,
-            completed_steps: *completed_steps,
-            total_steps,
+            completed_steps: steps.completed,
             status: BenchStepStatus::Finished,
+            elapsed_ms,
+            ops_per_sec: rate.ops_per_sec(),
+            ..base
         });
+        current = iterations.next();
     }
+    tracing::info!("{} done. last_digest={:016x}", name, last);
+    Ok(RawTimes {
+        digest: last,
+        times,
+        cold_ms,
+        retries,
+        spans,
+        chunk_stride: stride,
+    })
+}
 
-    let mut times: Vec<f64> = Vec::with_capacity(repeats);
-    for i in 0..repeats {
-        progress(ProgressUpdate {
-            bench_id: name,
-            phase: BenchPhase::Measure,
-            index: i + 1,
-            total: repeats,
-            completed_steps: *completed_steps,
-            total_steps,
-            status: BenchStepStatus::Started,
-        });
-        let t0 = Instant::now();
-        last = f();
-        times.push(t0.elapsed().as_secs_f64() * 1000.0);
-        *completed_steps += 1;
-        progress(ProgressUpdate {
-            bench_id: name,
-            phase: BenchPhase::Measure,
-            index: i + 1,
-            total: repeats,
-            completed_steps: *completed_steps,
-            total_steps,
-            status: BenchStepStatus::Finished,
+// 以 p50 耗时换算吞吐量
+fn throughput(spec: &CaseDef, stats: &BenchStats) -> Option<Throughput> {
+    spec.work.as_ref().map(|work| Throughput {
+        value: spec.n as f64 * work.per_n / (stats.p50 / 1000.0) / work.scale,
+        unit: work.unit,
+    })
+}
+
+fn case_result(
+    spec: &CaseDef,
+    mut raw: RawTimes,
+    digest_ok: Option<bool>,
+    first_in_process: bool,
+) -> BenchCaseResult {
+    let stats = calc_stats(&mut raw.times);
+    let throughput = throughput(spec, &stats);
+    let cold = raw.cold_ms.map(|time_ms| ColdPass {
+        time_ms,
+        first_in_process,
+        ratio: time_ms / stats.p50,
+    });
+    BenchCaseResult {
+        id: spec.id,
+        outcome: CaseOutcome::Ok,
+        error: None,
+        skip_reason: None,
+        digest: raw.digest,
+        digest_ok,
+        stats,
+        throughput,
+        cold,
+        relative: None,
+        retries: raw.retries,
+        verdict: None,
+        chunk_stride: raw.chunk_stride,
+    }
+}
+
+// 保留 3 位小数。用例结果的 time 块按设置的单位与位数输出，不经这里；ms3 用于吞吐、比值、
+// 重跑记录与噪声探测等数值，以及 A/B、累计统计等固定以毫秒输出的字段
+pub(crate) fn ms3(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+// 计时分辨率与开销可能在微秒以下，保留到纳秒
+pub(crate) fn ms6(value: f64) -> f64 {
+    (value * 1e6).round() / 1e6
+}
+
+// 按设定的单位换算并保留小数位
+fn time_value(config: &BenchmarkConfig, ms: f64) -> f64 {
+    let scale = 10f64.powi(config.time_decimals as i32);
+    (config.time_unit.convert_ms(ms) * scale).round() / scale
+}
+
+const FNV_OFFSET: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+fn fnv1a(acc: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(acc, |acc, &b| (acc ^ b as u64).wrapping_mul(FNV_PRIME))
+}
+
+// 整套 digest：按注册顺序对每个用例的 id、分隔字节 0 与 digest（小端 8 字节）做 FNV-1a 64。
+// 没跑完的用例以 0xFF 一个字节代替 digest，跳过、失败与用例增减都会改变结果；
+// 异或两个 digest 会丢信息，用例一多也很容易撞上
+pub fn suite_digest(digests: &[(&str, Option<u64>)]) -> u64 {
+    digests.iter().fold(FNV_OFFSET, |acc, (id, digest)| {
+        let acc = fnv1a(fnv1a(acc, id.as_bytes()), &[0]);
+        match digest {
+            Some(digest) => fnv1a(acc, &digest.to_le_bytes()),
+            None => fnv1a(acc, &[0xFF]),
+        }
+    })
+}
+
+pub(crate) fn hex(digest: u64) -> String {
+    format!("{:016x}", digest)
+}
+
+fn failed_result(spec: &CaseDef, outcome: CaseOutcome, error: Option<String>) -> BenchCaseResult {
+    BenchCaseResult {
+        id: spec.id,
+        outcome,
+        error,
+        skip_reason: None,
+        digest: 0,
+        digest_ok: None,
+        stats: BenchStats::default(),
+        throughput: None,
+        cold: None,
+        relative: None,
+        retries: Vec::new(),
+        verdict: None,
+        chunk_stride: 1,
+    }
+}
+
+fn skipped_result(spec: &CaseDef, reason: String) -> BenchCaseResult {
+    BenchCaseResult {
+        skip_reason: Some(reason),
+        ..failed_result(spec, CaseOutcome::Skipped, None)
+    }
+}
+
+// 整套运行的结论。计划内的跳过只计数，不算失败；取消或中止后没跑的用例记为中止
+pub struct SuiteOutcome {
+    pub failures: Vec<String>,
+    pub halted: bool,
+    pub skipped: usize,
+}
+
+pub fn suite_outcome(cases: &[BenchCaseResult]) -> SuiteOutcome {
+    SuiteOutcome {
+        failures: cases.iter().filter_map(|case| case.error.clone()).collect(),
+        halted: cases.iter().any(|case| {
+            case.outcome == CaseOutcome::Cancelled
+                || (case.outcome == CaseOutcome::Skipped && case.skip_reason.is_none())
+        }),
+        skipped: cases
+            .iter()
+            .filter(|case| case.skip_reason.is_some())
+            .count(),
+    }
+}
+
+fn n_overrides_json(config: &BenchmarkConfig) -> Value {
+    config
+        .n_overrides
+        .iter()
+        .filter_map(|&(id, n)| {
+            let case = find_case(id)?;
+            Some((id.to_string(), json!({ "n": n, "default_n": case.n })))
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+fn case_json(case: &BenchCaseResult, config: &BenchmarkConfig) -> Value {
+    if case.outcome != CaseOutcome::Ok {
+        let mut entry = json!({ "id": case.id, "outcome": case.outcome.as_str() });
+        if let Some(error) = &case.error {
+            entry["error"] = json!(error);
+        }
+        if let Some(reason) = &case.skip_reason {
+            entry["skip_reason"] = json!(reason);
+        }
+        return entry;
+    }
+
+    let time = |ms: f64| time_value(config, ms);
+    let unit = config.time_unit;
+    let mut entry = json!({
+        "id": case.id,
+        "outcome": case.outcome.as_str(),
+        "digest_u64": hex(case.digest),
+        unit.key(): {
+            "unit": unit.as_str(),
+            "min": time(case.stats.min),
+            "p50": time(case.stats.p50),
+            "p95": time(case.stats.p95),
+            "max": time(case.stats.max),
+            "mean": time(case.stats.mean),
+            "stddev": time(case.stats.stddev),
+        },
+    });
+    if let Some(ok) = case.digest_ok {
+        entry["digest_ok"] = json!(ok);
+    }
+    if let Some(t) = &case.throughput {
+        entry["throughput"] = json!({ "value": ms3(t.value), "unit": t.unit });
+    }
+    if let Some(cold) = &case.cold {
+        entry["cold"] = json!({
+            unit.key(): time(cold.time_ms),
+            "first_in_process": cold.first_in_process,
+            "cold_warm_ratio": ms3(cold.ratio),
         });
     }
-    tracing::info!("{} done. last_digest={:016x}", name, last);
-    (last, times)
+    if let Some(relative) = &case.relative {
+        entry["relative"] = relative_json(relative);
+    }
+    if let Some(verdict) = &case.verdict {
+        entry["verdict"] = json!(verdict.as_str());
+    }
+    if case.chunk_stride > 1 {
+        entry["chunk_stride"] = json!(case.chunk_stride);
+    }
+    if let Some(custom) = custom::custom_json(case.id) {
+        entry["custom"] = custom;
+    }
+    if !case.retries.is_empty() {
+        entry["retries"] = case
+            .retries
+            .iter()
+            .map(|retry| {
+                json!({
+                    "index": retry.index,
+                    "discarded_ms": ms3(retry.discarded_ms),
+                    "median_ms": ms3(retry.median_ms),
+                })
+            })
+            .collect();
+    }
+    entry
+}
+
+fn relative_json(relative: &Relative) -> Value {
+    let mut entry = json!({ "baseline": relative.baseline, "ratio": ms3(relative.ratio) });
+    if let Some(flagged) = relative.flagged {
+        entry["flagged"] = json!(flagged);
+    }
+    entry
 }
 
-fn calc_stats(times: &mut [f64]) -> BenchStats {
-    times.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    BenchStats {
-        min: times.first().copied().unwrap_or(0.0),
-        p50: median(times),
-        p95: p95(times),
-        max: times.last().copied().unwrap_or(0.0),
+// 对照用例需要先跑完才能算比值，所以在整个套件结束后统一补上
+fn attach_relative(cases: &mut [BenchCaseResult]) {
+    let p50_of = |cases: &[BenchCaseResult], id: &str| {
+        cases
+            .iter()
+            .find(|case| case.id == id && case.outcome == CaseOutcome::Ok)
+            .map(|case| case.stats.p50)
+    };
+    for i in 0..cases.len() {
+        if cases[i].outcome != CaseOutcome::Ok {
+            continue;
+        }
+        let Some(baseline) = find_case(cases[i].id).and_then(|def| def.baseline.as_ref()) else {
+            continue;
+        };
+        if let Some(base) = p50_of(cases, baseline.id) {
+            let ratio = cases[i].stats.p50 / base;
+            cases[i].relative = Some(Relative {
+                baseline: baseline.id,
+                ratio,
+                flagged: baseline.warn_above.map(|limit| ratio > limit),
+            });
+        }
     }
 }
 
-pub fn run_benchmark<P>(mut progress: P) -> BenchmarkResult
+struct Suite<'a, P, C> {
+    config: &'a BenchmarkConfig,
+    progress: P,
+    on_case: C,
+    steps: StepCounter,
+    cases: Vec<BenchCaseResult>,
+    // 与 cases 一一对应，用例结束时即写入；relative 在套件结束后补上
+    results: Vec<Value>,
+    halted: bool,
+    trace_origin: Option<Instant>,
+    trace: Vec<ChunkSpan>,
+}
+
+impl<P, C> Suite<'_, P, C>
 where
     P: FnMut(ProgressUpdate),
+    C: FnMut(&BenchCaseResult),
 {
-    let mut completed_steps = 0usize;
-    let (d1, mut t1) = run_bench(
-        "T1_INT32_MIX",
-        BENCH_WARMUP,
-        BENCH_REPEATS,
-        || bench_int32_mix(BENCH_SEED, BENCH_N1) as u64,
-        &mut progress,
-        &mut completed_steps,
-        TOTAL_STEPS,
-    );
-
-    let (d2, mut t2) = run_bench(
-        "T2_FP64_DOT",
-        BENCH_WARMUP,
-        BENCH_REPEATS,
-        || bench_fp64_dot(BENCH_SEED, BENCH_N2),
-        &mut progress,
-        &mut completed_steps,
-        TOTAL_STEPS,
-    );
-
-    let t1_stats = calc_stats(&mut t1);
-    let t2_stats = calc_stats(&mut t2);
-    let final_digest = d1 ^ d2;
-
-    let json = format!(
-        r#"{{
-  "lang": "rust",
-  "seed": {seed},
-  "params": {{ "n1": {n1}, "n2": {n2}, "warmup": {warmup}, "repeats": {repeats} }},
-  "results": [
-    {{
-      "id": "T1_INT32_MIX",
-      "digest_u64": "{d1:016x}",
-      "time_ms": {{ "min": {t1min:.3}, "p50": {t1p50:.3}, "p95": {t1p95:.3}, "max": {t1max:.3} }}
-    }},
-    {{
-      "id": "T2_FP64_DOT",
-      "digest_u64": "{d2:016x}",
-      "time_ms": {{ "min": {t2min:.3}, "p50": {t2p50:.3}, "p95": {t2p95:.3}, "max": {t2max:.3} }}
-    }}
-  ],
-  "final_digest_u64": "{final_digest:016x}"
-}}"#,
-        seed = BENCH_SEED,
-        n1 = BENCH_N1,
-        n2 = BENCH_N2,
-        warmup = BENCH_WARMUP,
-        repeats = BENCH_REPEATS,
-        d1 = d1,
-        d2 = d2,
-        t1min = t1_stats.min,
-        t1p50 = t1_stats.p50,
-        t1p95 = t1_stats.p95,
-        t1max = t1_stats.max,
-        t2min = t2_stats.min,
-        t2p50 = t2_stats.p50,
-        t2p95 = t2_stats.p95,
-        t2max = t2_stats.max,
-        final_digest = final_digest
-    );
+    // 每个用例结束（含失败与跳过）都经过这里，调用方可立即展示该用例的结果
+    fn finish(&mut self, mut case: BenchCaseResult) {
+        if let Some(threshold) = self.config.threshold(case.id) {
+            case.verdict = Some(evaluate(threshold, &case));
+        }
+        (self.on_case)(&case);
+        self.results.push(case_json(&case, self.config));
+        self.cases.push(case);
+    }
 
-    BenchmarkResult {
-        t1: BenchCaseResult {
-            id: "T1_INT32_MIX",
-            digest: d1,
-            stats: t1_stats,
+    // 返回 None 表示用例没有跑完（失败、取消或被跳过）
+    fn run<F>(&mut self, spec: &CaseDef, f: F) -> Option<u64>
+    where
+        F: FnMut(Stage, &mut OnChunk) -> Result<u64, String>,
+    {
+        if self.halted {
+            self.finish(failed_result(spec, CaseOutcome::Skipped, None));
+            return None;
+        }
+
+        let reference = spec.reference.filter(|_| self.config.verify_digests);
+        let guard = RunGuard {
+            trace_origin: self.trace_origin,
+            ..RunGuard::for_config(self.config, reference)
+        };
+        let cold = if self.config.cold_start { 1 } else { 0 };
+        let plan = [
+            (BenchPhase::Cold, cold),
+            (BenchPhase::Warmup, self.config.warmup),
+            (BenchPhase::Measure, self.config.repeats),
+        ];
+        let first_in_process = {
+            let mut executed = EXECUTED_CASES
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let first = !executed.contains(&spec.id);
+            if first {
+                executed.push(spec.id);
+            }
+            first
+        };
+
+        match run_bench(spec, &plan, &guard, f, &mut self.progress, &mut self.steps) {
+            Ok(mut raw) => {
+                self.trace.append(&mut raw.spans);
+                let digest = raw.digest;
+                let digest_ok = reference.map(|expected| expected == digest);
+                self.finish(case_result(spec, raw, digest_ok, first_in_process));
+                Some(digest)
+            }
+            Err(err) => {
+                let outcome = match err {
+                    BenchError::Cancelled { .. } => CaseOutcome::Cancelled,
+                    _ => CaseOutcome::Failed,
+                };
+                self.halted = outcome == CaseOutcome::Cancelled
+                    || self.config.failure_policy == FailurePolicy::AbortSuite;
+                self.finish(failed_result(spec, outcome, Some(err.to_string())));
+                None
+            }
+        }
+    }
+}
+
+// on_case 在每个用例结束时立即回调，不必等整套跑完；relative 要等对照用例跑完，
+// 只出现在最终的 BenchmarkResult 里
+pub fn run_benchmark<P, C>(
+    config: &BenchmarkConfig,
+    host: &dyn Host,
+    progress: P,
+    on_case: C,
+) -> BenchmarkResult
+where
+    P: FnMut(ProgressUpdate),
+    C: FnMut(&BenchCaseResult),
+{
+    clear_requests();
+    let started_unix_ms = timestamp::now_unix_ms();
+    let timer = timer_probe();
+    if timer.is_coarse() {
+        tracing::warn!("timer resolution is coarse: {} ms", timer.resolution_ms);
+    }
+    let noise = noise_probe();
+    if noise.is_busy() {
+        tracing::warn!("device looks busy: noise score {:.3}", noise.score);
+    }
+    let barrier = config.barrier;
+    let mut suite = Suite {
+        config,
+        progress,
+        on_case,
+        steps: StepCounter {
+            completed: 0,
+            total: total_steps(config),
+        },
+        cases: Vec::with_capacity(CASE_COUNT),
+        results: Vec::with_capacity(CASE_COUNT),
+        halted: false,
+        trace_origin: config.export_trace.then(Instant::now),
+        trace: Vec::new(),
+    };
+
+    let mut workspace = Workspace::default();
+    let mut digests = Vec::with_capacity(CASE_COUNT);
+    let mut guardrails = Vec::new();
+    for case in all_cases() {
+        // 覆盖了迭代数的用例换成规模不同、没有参考 digest 的一份定义
+        let overridden;
+        let case = match config.n_override(case.id) {
+            Some(n) => {
+                overridden = CaseDef {
+                    n,
+                    reference: None,
+                    ..*case
+                };
+                &overridden
+            }
+            None => case,
+        };
+        if config.skip_cases.contains(&case.id) {
+            let reason = if config.device_class.defaults().skip_cases.contains(&case.id) {
+                format!(
+                    "{} 按设备类别 {} 默认跳过",
+                    case.id,
+                    config.device_class.as_str()
+                )
+            } else {
+                format!("{} 未选中", case.id)
+            };
+            suite.finish(skipped_result(case, reason));
+            digests.push((case.id, None));
+            continue;
+        }
+        if case.needs_render && config.render_target.is_none() {
+            suite.finish(skipped_result(
+                case,
+                format!("{} 需要宿主渲染目标", case.id),
+            ));
+            digests.push((case.id, None));
+            continue;
+        }
+        if !suite.halted
+            && let Some(decision) = guardrail::check(case, &mut workspace)
+        {
+            let skipped = decision.action == guardrail::Action::Skipped;
+            let reason = decision.reason();
+            guardrails.push(decision);
+            if skipped {
+                suite.finish(skipped_result(case, reason));
+                digests.push((case.id, None));
+                continue;
+            }
+        }
+        if let Some(prepare) = case.prepare
+            && !suite.halted
+        {
+            prepare(&mut workspace, BENCH_SEED);
+        }
+        let mut run = CaseRun {
+            seed: BENCH_SEED,
+            n: case.n,
+            barrier,
+            chunks: config.chunks,
+            chunk_stride: 1,
+            workspace: &mut workspace,
+            render_target: config.render_target.as_deref(),
+            host,
+            error: None,
+        };
+        let digest = suite.run(case, |stage, on_chunk| match stage {
+            Stage::Reset => {
+                case.reset_workspace(run.workspace, run.seed);
+                Ok(0)
+            }
+            Stage::Execute { stride } => {
+                run.chunk_stride = stride;
+                let raw = (case.run)(&mut run, on_chunk);
+                run.error.take().map_or(Ok(raw), Err)
+            }
+            Stage::Finalize(raw) => Ok(case.digest(run.workspace, raw)),
+        });
+        digests.push((case.id, digest));
+    }
+
+    // final_digest 只覆盖 T1/T2，保持与其他语言移植版可直接比对
+    let digest_of = |id: &str| {
+        digests
+            .iter()
+            .find(|(case_id, _)| *case_id == id)
+            .and_then(|(_, digest)| *digest)
+            .unwrap_or(0)
+    };
+    let final_digest = (config.n1() == BENCH_N1 && config.n2() == BENCH_N2)
+        .then(|| digest_of("T1_INT32_MIX") ^ digest_of("T2_FP64_DOT"));
+    let suite_digest = suite_digest(&digests);
+    let conditions_before = Conditions::from_probe(&noise);
+    let conditions_after =
+        (!CANCEL_REQUESTED.load(Ordering::Relaxed)).then(|| Conditions::from_probe(&noise_probe()));
+    if let Some(after) = &conditions_after
+        && conditions_before.compare(after).significant
+    {
+        tracing::warn!(
+            "conditions changed during run: loop p50 {:.3} -> {:.3} us",
+            conditions_before.loop_p50_us,
+            after.loop_p50_us
+        );
+    }
+    let mut cases = suite.cases;
+    let mut results = suite.results;
+    let trace = suite.trace;
+    attach_relative(&mut cases);
+    for (case, entry) in cases.iter().zip(results.iter_mut()) {
+        if let Some(relative) = &case.relative {
+            entry["relative"] = relative_json(relative);
+        }
+    }
+
+    let json = json!({
+        "lang": "rust",
+        "started_at": timestamp::iso8601_utc(started_unix_ms),
+        "env": host.env_json(),
+        "build": build_info::json(),
+        "seed": BENCH_SEED,
+        "params": {
+            "n1": config.n1(),
+            "n2": config.n2(),
+            "warmup": config.warmup,
+            "repeats": config.repeats,
+            "mem_bytes": BENCH_MEM_BYTES,
+            "mem_passes": BENCH_MEM_PASSES,
+            "barrier": barrier.as_str(),
+            "verify_digests": config.verify_digests,
+            "abort_on_mismatch": config.abort_on_mismatch,
+            "case_timeout_ms": config.case_timeout_ms,
+            "cold_start": config.cold_start,
+            "timer_correction": config.timer_correction,
+            "retry_noisy": config.retry_noisy,
+            "export_trace": config.export_trace,
+            "batch_chunks": config.batch_chunks,
+            "time_unit": config.time_unit.as_str(),
+            "time_decimals": config.time_decimals,
+        },
+        // 实际运行的规模，含迭代数覆盖。n1/n2 从不截断，capped 恒为 false，方便与其他语言移植版对照
+        "effective_params": {
+            "n1": config.n1(),
+            "n2": config.n2(),
+            "capped": false,
+            "chunks": config.chunks,
+            "timer_overhead_ms": ms6(timer_overhead(config).as_secs_f64() * 1000.0),
+            "retry": config.retry_noisy.then(|| json!({
+                "factor": RETRY_FACTOR,
+                "budget": RETRY_BUDGET,
+            })),
+            // 设置里覆盖过迭代数的用例，没有覆盖时为空对象
+            "n_overrides": n_overrides_json(config),
+            // 内存护栏的处理记录，分配都顺利时为空
+            "guardrails": guardrails.iter().map(guardrail::Decision::json).collect::<Vec<_>>(),
         },
-        t2: BenchCaseResult {
-            id: "T2_FP64_DOT",
-            digest: d2,
-            stats: t2_stats,
+        "device_class": {
+            "class": config.device_class.as_str(),
+            "warmup": config.warmup,
+            "repeats": config.repeats,
+            "skipped": config.skip_cases,
         },
+        "policy": config.failure_policy.as_str(),
+        "conditions": noise.json(),
+        "conditions_delta": conditions_after
+            .as_ref()
+            .map(|after| delta_json(&conditions_before, after)),
+        "results": results,
+        "thresholds": thresholds_json(&config.thresholds, &cases),
+        "offload": { "available": OFFLOAD_API.is_some(), "api": OFFLOAD_API },
+        // 能耗只能粗估，可用时各用例的值也带 estimate 标记
+        "energy": {
+            "available": BATTERY_API.is_some(),
+            "api": BATTERY_API,
+            "estimate": true,
+            "unit": "mJ/Mop",
+            "per_case": null,
+        },
+        "suite_digest": hex(suite_digest),
+        // 过渡期保留：只覆盖 T1/T2 的异或，旧版解析器与其他语言移植版仍读这个字段。
+        // T1/T2 按覆盖后的规模运行时与参考值不可比，输出 null
+        "final_digest_u64": final_digest.map(hex),
+    });
+
+    BenchmarkResult {
+        cases,
+        policy: config.failure_policy,
+        noise,
+        conditions_before,
+        conditions_after,
         final_digest,
-        json,
+        suite_digest,
+        started_unix_ms,
+        json: serde_json::to_string_pretty(&json).unwrap_or_default(),
+        trace,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(id: &str) -> &'static CaseDef {
+        find_case(id).unwrap()
+    }
+
+    fn ok(id: &str) -> BenchCaseResult {
+        failed_result(case(id), CaseOutcome::Ok, None)
+    }
+
+    fn failed(id: &str, outcome: CaseOutcome) -> BenchCaseResult {
+        failed_result(case(id), outcome, Some(format!("{} 出错", id)))
+    }
+
+    #[test]
+    fn planned_skip_is_not_a_failure() {
+        // 手机上 T17 按设备类别默认跳过
+        let cases = [
+            ok("T1_INT32_MIX"),
+            skipped_result(
+                case("T17_UI_RENDER"),
+                "T17_UI_RENDER 按设备类别 phone 默认跳过".into(),
+            ),
+        ];
+        let outcome = suite_outcome(&cases);
+        assert!(outcome.failures.is_empty());
+        assert!(!outcome.halted);
+        assert_eq!(outcome.skipped, 1);
+    }
+
+    #[test]
+    fn skip_reason_stays_out_of_error() {
+        let skipped = skipped_result(
+            case("T17_UI_RENDER"),
+            "T17_UI_RENDER 需要宿主渲染目标".into(),
+        );
+        assert!(skipped.outcome == CaseOutcome::Skipped);
+        assert!(skipped.error.is_none());
+        let entry = case_json(&skipped, &BenchmarkConfig::default());
+        assert_eq!(entry["outcome"], "skipped");
+        assert!(entry.get("error").is_none());
+        assert_eq!(entry["skip_reason"], "T17_UI_RENDER 需要宿主渲染目标");
+    }
+
+    #[test]
+    fn failure_with_continue_policy_is_not_halted() {
+        let cases = [
+            failed("T1_INT32_MIX", CaseOutcome::Failed),
+            ok("T2_FP64_DOT"),
+        ];
+        let outcome = suite_outcome(&cases);
+        assert_eq!(outcome.failures, ["T1_INT32_MIX 出错"]);
+        assert!(!outcome.halted);
+        assert_eq!(outcome.skipped, 0);
+    }
+
+    #[test]
+    fn abort_policy_skips_count_as_halted() {
+        // AbortSuite 下失败之后的用例不带原因地跳过
+        let cases = [
+            failed("T1_INT32_MIX", CaseOutcome::Failed),
+            failed_result(case("T2_FP64_DOT"), CaseOutcome::Skipped, None),
+        ];
+        let outcome = suite_outcome(&cases);
+        assert_eq!(outcome.failures.len(), 1);
+        assert!(outcome.halted);
+        assert_eq!(outcome.skipped, 0);
+    }
+
+    #[test]
+    fn cancel_is_halted() {
+        let cases = [
+            ok("T1_INT32_MIX"),
+            failed("T2_FP64_DOT", CaseOutcome::Cancelled),
+        ];
+        let outcome = suite_outcome(&cases);
+        assert_eq!(outcome.failures.len(), 1);
+        assert!(outcome.halted);
     }
 }
@@ -0,0 +1,286 @@
+// A/B 对比：同一会话内用两套配置交替运行每个用例。按 ABBA 顺序分成 AB_PASSES 段，
+// 两边经历的升温/降频大致相同，比值不会被热漂移带偏
+use std::sync::atomic::Ordering;
+
+use serde_json::{Value, json};
+
+use crate::{build_info, timestamp};
+
+use super::cases::{CaseRun, Workspace};
+use super::{
+    BENCH_SEED, BenchPhase, BenchStats, BenchmarkConfig, CANCEL_REQUESTED, CASES, CaseDef, Host,
+    MAX_CHUNKS, ProgressUpdate, RunGuard, Stage, StepCounter, Welford, calc_stats, clear_requests,
+    ms3, reference_digest, run_bench,
+};
+
+pub const AB_PASSES: usize = 4;
+
+// B 相对 A 只改一个变量，界面上循环切换
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AbVariant {
+    Barrier,
+    Chunks,
+    TimerCorrection,
+}
+
+impl AbVariant {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AbVariant::Barrier => "barrier",
+            AbVariant::Chunks => "chunks",
+            AbVariant::TimerCorrection => "timer_correction",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            AbVariant::Barrier => AbVariant::Chunks,
+            AbVariant::Chunks => AbVariant::TimerCorrection,
+            AbVariant::TimerCorrection => AbVariant::Barrier,
+        }
+    }
+
+    // 以 a 为基础生成 B 配置
+    pub fn apply(self, a: &BenchmarkConfig) -> BenchmarkConfig {
+        let mut b = a.clone();
+        match self {
+            AbVariant::Barrier => b.barrier = a.barrier.toggled(),
+            AbVariant::Chunks => b.chunks = if a.chunks == 1 { MAX_CHUNKS } else { 1 },
+            AbVariant::TimerCorrection => b.timer_correction = !a.timer_correction,
+        }
+        b
+    }
+
+    // 该变量在某套配置下的取值，用于标注 A/B
+    pub fn describe(self, config: &BenchmarkConfig) -> String {
+        match self {
+            AbVariant::Barrier => config.barrier.as_str().to_string(),
+            AbVariant::Chunks => format!("chunks={}", config.chunks),
+            AbVariant::TimerCorrection => format!("timer_correction={}", config.timer_correction),
+        }
+    }
+}
+
+pub struct AbRow {
+    pub id: &'static str,
+    pub a: Option<BenchStats>,
+    pub b: Option<BenchStats>,
+    // 任一侧失败时的原因
+    pub error: Option<String>,
+}
+
+impl AbRow {
+    // B 相对 A 的 p50 变化，正数表示 B 更慢
+    pub fn delta_p50(&self) -> Option<f64> {
+        match (&self.a, &self.b) {
+            (Some(a), Some(b)) if a.p50 > 0.0 => Some(b.p50 / a.p50 - 1.0),
+            _ => None,
+        }
+    }
+}
+
+pub struct AbResult {
+    pub variant: AbVariant,
+    pub label_a: String,
+    pub label_b: String,
+    pub rows: Vec<AbRow>,
+    pub cancelled: bool,
+    pub started_unix_ms: u64,
+    pub json: String,
+}
+
+pub(super) fn ab_cases(config: &BenchmarkConfig) -> impl Iterator<Item = &'static CaseDef> + '_ {
+    CASES.iter().filter(move |case| {
+        !config.skip_cases.contains(&case.id)
+            && (!case.needs_render || config.render_target.is_some())
+    })
+}
+
+// 第 pass 段分到的正式迭代数，余数从前面的段开始分
+fn pass_repeats(repeats: usize, pass: usize) -> usize {
+    repeats / AB_PASSES + usize::from(pass < repeats % AB_PASSES)
+}
+
+pub fn total_steps(config: &BenchmarkConfig) -> usize {
+    ab_cases(config).count() * 2 * (config.warmup + config.repeats)
+}
+
+fn stats_json(stats: &BenchStats) -> Value {
+    json!({
+        "min": ms3(stats.min),
+        "p50": ms3(stats.p50),
+        "p95": ms3(stats.p95),
+        "max": ms3(stats.max),
+        "mean": ms3(stats.mean),
+        "stddev": ms3(stats.stddev),
+    })
+}
+
+pub fn run_ab<P>(
+    a: &BenchmarkConfig,
+    variant: AbVariant,
+    host: &dyn Host,
+    mut progress: P,
+) -> AbResult
+where
+    P: FnMut(ProgressUpdate),
+{
+    clear_requests();
+    let started_unix_ms = timestamp::now_unix_ms();
+    let b = variant.apply(a);
+    let sides = [a, &b];
+    let mut steps = StepCounter {
+        completed: 0,
+        total: total_steps(a),
+    };
+    let mut workspace = Workspace::default();
+    let mut rows = Vec::new();
+    let mut cancelled = false;
+
+    for case in ab_cases(a) {
+        if let Some(prepare) = case.prepare {
+            prepare(&mut workspace, BENCH_SEED);
+        }
+        let reference = reference_digest(case.id).filter(|_| a.verify_digests);
+        let mut times: [Vec<f64>; 2] = [Vec::new(), Vec::new()];
+        // 每段单独累积后并入，均值与标准差按全部段的样本计算
+        let mut moments = [Welford::default(); 2];
+        let mut error = None;
+
+        'passes: for pass in 0..AB_PASSES {
+            let order = if pass % 2 == 0 { [0, 1] } else { [1, 0] };
+            for side in order {
+                let config = sides[side];
+                let warmup = if pass == 0 { config.warmup } else { 0 };
+                let plan = [
+                    (BenchPhase::Warmup, warmup),
+                    (BenchPhase::Measure, pass_repeats(config.repeats, pass)),
+                ];
+                let guard = RunGuard::for_config(config, reference);
+                let run = run_bench(
+                    case,
+                    &plan,
+                    &guard,
+                    |stage, on_chunk| {
+                        let stride = match stage {
+                            Stage::Reset => {
+                                case.reset_workspace(&mut workspace, BENCH_SEED);
+                                return Ok(0);
+                            }
+                            Stage::Execute { stride } => stride,
+                            Stage::Finalize(raw) => return Ok(case.digest(&workspace, raw)),
+                        };
+                        let mut run = CaseRun {
+                            seed: BENCH_SEED,
+                            n: case.n,
+                            barrier: config.barrier,
+                            chunks: config.chunks,
+                            chunk_stride: stride,
+                            workspace: &mut workspace,
+                            render_target: config.render_target.as_deref(),
+                            host,
+                            error: None,
+                        };
+                        let raw = (case.run)(&mut run, on_chunk);
+                        run.error.take().map_or(Ok(raw), Err)
+                    },
+                    &mut progress,
+                    &mut steps,
+                );
+                match run {
+                    Ok(raw) => {
+                        let pass: Welford = raw.times.iter().copied().collect();
+                        moments[side].merge(&pass);
+                        times[side].extend(raw.times);
+                    }
+                    Err(err) => {
+                        cancelled = CANCEL_REQUESTED.load(Ordering::Relaxed);
+                        let name = if side == 0 { "A" } else { "B" };
+                        error = Some(format!("{}: {}", name, err));
+                        break 'passes;
+                    }
+                }
+            }
+        }
+
+        let side_stats = |times: &mut Vec<f64>, moments: &Welford| BenchStats {
+            mean: moments.mean(),
+            stddev: moments.stddev(),
+            ..calc_stats(times)
+        };
+        let [mut times_a, mut times_b] = times;
+        rows.push(if error.is_some() {
+            AbRow {
+                id: case.id,
+                a: None,
+                b: None,
+                error,
+            }
+        } else {
+            AbRow {
+                id: case.id,
+                a: Some(side_stats(&mut times_a, &moments[0])),
+                b: Some(side_stats(&mut times_b, &moments[1])),
+                error: None,
+            }
+        });
+        if cancelled {
+            break;
+        }
+    }
+
+    let label_a = variant.describe(a);
+    let label_b = variant.describe(&b);
+    tracing::info!(
+        "a/b done. variant={} a={} b={} cases={}",
+        variant.as_str(),
+        label_a,
+        label_b,
+        rows.len()
+    );
+
+    let json = json!({
+        "lang": "rust",
+        "started_at": timestamp::iso8601_utc(started_unix_ms),
+        "env": host.env_json(),
+        "build": build_info::json(),
+        "seed": BENCH_SEED,
+        "profile": "ab",
+        "params": {
+            "variant": variant.as_str(),
+            "a": label_a,
+            "b": label_b,
+            "passes": AB_PASSES,
+            "warmup": a.warmup,
+            "repeats": a.repeats,
+        },
+        "cancelled": cancelled,
+        "rows": rows
+            .iter()
+            .map(|row| {
+                let mut entry = json!({ "id": row.id });
+                if let (Some(a), Some(b)) = (&row.a, &row.b) {
+                    entry["a"] = stats_json(a);
+                    entry["b"] = stats_json(b);
+                }
+                if let Some(delta) = row.delta_p50() {
+                    entry["delta_p50"] = json!(ms3(delta));
+                }
+                if let Some(error) = &row.error {
+                    entry["error"] = json!(error);
+                }
+                entry
+            })
+            .collect::<Vec<_>>(),
+    });
+
+    AbResult {
+        variant,
+        label_a,
+        label_b,
+        rows,
+        cancelled,
+        started_unix_ms,
+        json: serde_json::to_string_pretty(&json).unwrap_or_default(),
+    }
+}
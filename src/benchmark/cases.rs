@@ -0,0 +1,745 @@
+// 用例注册表。新增用例时在 cases/ 下实现内核，再登记到 CASES 末尾；
+// 注册顺序即执行顺序，也是 JSON results 的顺序
+mod audio;
+mod bounds;
+mod checksum;
+mod cipher;
+pub(super) mod compute;
+mod containers;
+mod denormal;
+mod fixed;
+mod host_ui;
+mod image;
+mod memory;
+mod ordered_map;
+mod shuffle;
+mod storage_io;
+mod summation;
+mod text;
+mod wide;
+
+use super::{BENCH_N1, BENCH_N2, Barrier, Host, custom};
+
+// 每个内核把 n 切成至多 chunks 块，每 stride 块及最后一块结束时调用 on_chunk(块序号, 块总数)。
+// 切块只改变循环边界，不改变运算顺序，digest 与整段运行一致
+pub type OnChunk<'a> = dyn FnMut(usize, usize) + 'a;
+
+pub fn chunk_len(n: u64, chunks: usize) -> u64 {
+    n.div_ceil(chunks.max(1) as u64).max(1)
+}
+
+#[inline(always)]
+pub fn for_each_chunk<F>(n: u64, chunks: usize, stride: usize, on_chunk: &mut OnChunk, mut body: F)
+where
+    F: FnMut(u64, u64),
+{
+    let len = chunk_len(n, chunks);
+    let chunks = n.div_ceil(len) as usize;
+    let stride = stride.max(1);
+    for chunk in 0..chunks {
+        let start = chunk as u64 * len;
+        body(start, (start + len).min(n));
+        let index = chunk + 1;
+        if index.is_multiple_of(stride) || index == chunks {
+            on_chunk(index, chunks);
+        }
+    }
+}
+
+// 用例之间复用的大块缓冲区，首次使用时分配
+#[derive(Default)]
+pub struct Workspace {
+    mem_src: Vec<u64>,
+    mem_dst: Vec<u64>,
+    indices: Vec<u32>,
+    series: Vec<f64>,
+    bytes: Vec<u8>,
+    image_src: Vec<u8>,
+    image: Vec<u8>,
+    image_tmp: Vec<u16>,
+    samples: Vec<f32>,
+    dot_fixed: Vec<i32>,
+    dot_float: Vec<f64>,
+    blobs: Vec<Vec<u8>>,
+    blob_digests: Vec<u64>,
+    words: Vec<u32>,
+    cipher_src: Vec<u32>,
+    cipher: Vec<u32>,
+}
+
+impl Workspace {
+    pub fn mem(&mut self, seed: u32) -> (&[u64], &mut [u64]) {
+        if self.mem_src.is_empty() {
+            self.mem_src = memory::fill_buffer(seed, memory::MEM_BYTES);
+            self.mem_dst = vec![0u64; self.mem_src.len()];
+        }
+        (&self.mem_src, &mut self.mem_dst)
+    }
+
+    pub fn words(&mut self, seed: u32) -> &[u32] {
+        if self.words.is_empty() {
+            self.words = bounds::fill_buffer(seed);
+        }
+        &self.words
+    }
+
+    // 加密内核原地改写，每次迭代前经 restore_cipher 从明文副本恢复
+    pub fn cipher(&mut self, seed: u32) -> &mut [u32] {
+        if self.cipher_src.is_empty() {
+            self.cipher_src = cipher::fill_buffer(seed);
+            self.cipher.clone_from(&self.cipher_src);
+        }
+        &mut self.cipher
+    }
+
+    pub fn restore_cipher(&mut self, seed: u32) {
+        self.cipher(seed);
+        self.cipher.clone_from(&self.cipher_src);
+    }
+
+    pub fn series(&mut self, seed: u32) -> &[f64] {
+        if self.series.is_empty() {
+            self.series = summation::fill_series(seed);
+        }
+        &self.series
+    }
+
+    pub fn bytes(&mut self, seed: u32) -> &[u8] {
+        if self.bytes.is_empty() {
+            self.bytes = checksum::fill_bytes(seed);
+        }
+        &self.bytes
+    }
+
+    // 模糊内核原地改写，每次迭代前经 restore_image 从原图恢复
+    pub fn image(&mut self, seed: u32) -> (&mut [u8], &mut [u16]) {
+        if self.image_src.is_empty() {
+            self.image_src = image::fill_image(seed);
+            self.image_tmp = vec![0u16; self.image_src.len()];
+            self.image.clone_from(&self.image_src);
+        }
+        (&mut self.image, &mut self.image_tmp)
+    }
+
+    pub fn restore_image(&mut self, seed: u32) {
+        self.image(seed);
+        self.image.clone_from(&self.image_src);
+    }
+
+    pub fn samples(&mut self, seed: u32) -> &[f32] {
+        if self.samples.is_empty() {
+            self.samples = audio::fill_samples(seed);
+        }
+        &self.samples
+    }
+
+    pub fn dot_inputs(&mut self, seed: u32) -> (&[i32], &[f64]) {
+        if self.dot_fixed.is_empty() {
+            (self.dot_fixed, self.dot_float) = fixed::fill_inputs(seed);
+        }
+        (&self.dot_fixed, &self.dot_float)
+    }
+
+    pub fn blobs(&mut self, seed: u32) -> &[Vec<u8>] {
+        if self.blobs.is_empty() {
+            self.blobs = storage_io::fill_blobs(seed);
+        }
+        &self.blobs
+    }
+
+    // 读回比对用的各数据块 FNV，与数据块一起生成一次
+    pub fn blob_digests(&mut self, seed: u32) -> &[u64] {
+        if self.blob_digests.is_empty() {
+            self.blob_digests = storage_io::blob_digests(self.blobs(seed));
+        }
+        &self.blob_digests
+    }
+
+    pub fn indices(&mut self, len: usize) -> &mut [u32] {
+        self.indices.resize(len, 0);
+        &mut self.indices
+    }
+
+    // 恢复为恒等排列
+    pub fn reset_indices(&mut self, len: usize) {
+        for (i, v) in self.indices(len).iter_mut().enumerate() {
+            *v = i as u32;
+        }
+    }
+
+    // 以下只读访问供 finalize 使用，取的是上一次运行留下的内容，不做初始化或恢复
+    pub fn mem_out(&self) -> &[u64] {
+        &self.mem_dst
+    }
+
+    pub fn image_out(&self) -> &[u8] {
+        &self.image
+    }
+
+    pub fn indices_out(&self) -> &[u32] {
+        &self.indices
+    }
+}
+
+pub struct CaseRun<'a> {
+    pub seed: u32,
+    pub n: u64,
+    pub barrier: Barrier,
+    // 进度上报的最大块数
+    pub chunks: usize,
+    // 合并块回调时每隔几块调用一次 on_chunk，为 1 时每块都调用
+    pub chunk_stride: usize,
+    pub workspace: &'a mut Workspace,
+    pub render_target: Option<&'a str>,
+    pub host: &'a dyn Host,
+    // 内核出错时记下原因并尽快返回，本次迭代判为失败
+    pub error: Option<String>,
+}
+
+impl CaseRun<'_> {
+    pub fn fail(&mut self, message: impl Into<String>) {
+        self.error.get_or_insert_with(|| message.into());
+    }
+}
+
+// 吞吐换算：每单位 n 的工作量，value = n * per_n / 秒 / scale
+#[derive(Clone, Copy)]
+pub struct Work {
+    pub per_n: f64,
+    pub scale: f64,
+    pub unit: &'static str,
+}
+
+// 对照用例：结果里附带本用例 p50 与对照 p50 之比，超过 warn_above 时标记为异常
+#[derive(Clone, Copy)]
+pub struct Baseline {
+    pub id: &'static str,
+    pub warn_above: Option<f64>,
+}
+
+#[derive(Clone, Copy)]
+pub struct CaseDef {
+    pub id: &'static str,
+    // 界面上展开的一句话说明：测什么、规模多大
+    pub description: &'static str,
+    // 默认规模：迭代次数、遍数或轮数，具体含义由内核决定
+    pub n: u64,
+    // 每单位 n 的基本操作数，用于滚动速度估计
+    pub ops_per_n: u64,
+    pub work: Option<Work>,
+    // 默认 seed 与 n 下的参考 digest，由原生构建运行得出
+    pub reference: Option<u64>,
+    pub baseline: Option<Baseline>,
+    // 运行时需要的大块内存（估计值），开跑前按它试探分配，不够时跳过该用例
+    pub buffer_bytes: usize,
+    // 在计时开始前分配缓冲区，避免把分配耗时算进首次迭代
+    pub prepare: Option<fn(&mut Workspace, u32)>,
+    // 原地改写输入的内核在每次迭代计时前把工作区恢复到初始输入，恢复本身不计时
+    pub reset: Option<fn(&mut Workspace, u32)>,
+    // 需要宿主的渲染目标，没有时（如原生单元测试）跳过
+    pub needs_render: bool,
+    pub run: fn(&mut CaseRun, &mut OnChunk) -> u64,
+    // 在计时结束后由 run 的返回值与工作区算出 digest；为 None 时 run 的返回值即 digest。
+    // 遍历整块输出缓冲区做折叠的用例放在这里，免得把校验本身算进耗时
+    pub finalize: Option<fn(&Workspace, u64) -> u64>,
+}
+
+impl CaseDef {
+    pub fn reset_workspace(&self, workspace: &mut Workspace, seed: u32) {
+        if let Some(reset) = self.reset {
+            reset(workspace, seed);
+        }
+    }
+
+    // 一次运行的 digest：有 finalize 时交给它，否则就是 run 的返回值
+    pub fn digest(&self, workspace: &Workspace, raw: u64) -> u64 {
+        self.finalize
+            .map_or(raw, |finalize| finalize(workspace, raw))
+    }
+}
+
+const MB_PER_PASS: Work = Work {
+    per_n: memory::MEM_BYTES as f64,
+    scale: 1e6,
+    unit: "MB/s",
+};
+
+const ELEM_PER_PASS: Work = Work {
+    per_n: bounds::LEN as f64,
+    scale: 1e6,
+    unit: "M elem/s",
+};
+
+const BYTES_PER_PASS: Work = Work {
+    per_n: checksum::BYTES as f64,
+    scale: 1e6,
+    unit: "MB/s",
+};
+
+const IO_PER_ROUND: Work = Work {
+    per_n: storage_io::BYTES_PER_ROUND as f64,
+    scale: 1e6,
+    unit: "MB/s",
+};
+
+pub const CASES: &[CaseDef] = &[
+    CaseDef {
+        id: "T1_INT32_MIX",
+        description: "3 亿次 32 位整数乘法、移位与异或混合运算，衡量整数 ALU 吞吐",
+        n: BENCH_N1,
+        ops_per_n: 1,
+        work: None,
+        reference: Some(0x0000_0000_fed8_15fc),
+        baseline: None,
+        buffer_bytes: 0,
+        prepare: None,
+        reset: None,
+        needs_render: false,
+        run: compute::int32_mix,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T2_FP64_DOT",
+        description: "2 亿次双精度浮点乘加累积，衡量 FPU 吞吐",
+        n: BENCH_N2,
+        ops_per_n: 1,
+        work: None,
+        reference: Some(0x4187_d78a_0d42_ead3),
+        baseline: None,
+        buffer_bytes: 0,
+        prepare: None,
+        reset: None,
+        needs_render: false,
+        run: compute::fp64_dot,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T3_MEM_READ",
+        description: "顺序读取 8 MiB 缓冲区 16 遍，衡量内存读带宽",
+        n: memory::MEM_PASSES,
+        ops_per_n: memory::MEM_WORDS,
+        work: Some(MB_PER_PASS),
+        reference: Some(0x9b6e_5b49_6179_5ea1),
+        baseline: None,
+        buffer_bytes: memory::BUFFER_BYTES,
+        prepare: Some(memory::prepare),
+        reset: None,
+        needs_render: false,
+        run: memory::read,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T3_MEM_WRITE",
+        description: "顺序写满 8 MiB 缓冲区 16 遍，衡量内存写带宽",
+        n: memory::MEM_PASSES,
+        ops_per_n: memory::MEM_WORDS,
+        work: Some(MB_PER_PASS),
+        reference: Some(0xe373_bef7_71f6_7625),
+        baseline: None,
+        buffer_bytes: memory::BUFFER_BYTES,
+        prepare: Some(memory::prepare),
+        reset: None,
+        needs_render: false,
+        run: memory::write,
+        finalize: Some(memory::finalize),
+    },
+    // copy 同时读源、写目标，按 STREAM 惯例计 2 倍字节数
+    CaseDef {
+        id: "T3_MEM_COPY",
+        description: "在两个 8 MiB 缓冲区之间复制 16 遍，衡量内存复制带宽",
+        n: memory::MEM_PASSES,
+        ops_per_n: memory::MEM_WORDS,
+        work: Some(Work {
+            per_n: 2.0 * memory::MEM_BYTES as f64,
+            scale: 1e6,
+            unit: "MB/s",
+        }),
+        reference: Some(0x964f_8490_390e_fde7),
+        baseline: None,
+        buffer_bytes: memory::BUFFER_BYTES,
+        prepare: Some(memory::prepare),
+        reset: None,
+        needs_render: false,
+        run: memory::copy,
+        finalize: Some(memory::finalize),
+    },
+    CaseDef {
+        id: "T4_SHUFFLE",
+        description: "对 100 万元素数组做 32 轮 Fisher–Yates 洗牌，衡量 PRNG、取模与随机写入",
+        n: shuffle::ROUNDS,
+        ops_per_n: shuffle::LEN as u64,
+        work: None,
+        reference: Some(0x33dd_f7c9_f98f_544f),
+        baseline: None,
+        buffer_bytes: shuffle::BUFFER_BYTES,
+        prepare: Some(shuffle::prepare),
+        reset: Some(shuffle::reset),
+        needs_render: false,
+        run: shuffle::run,
+        finalize: Some(shuffle::finalize),
+    },
+    CaseDef {
+        id: "T5_BOUNDS_INDEXED",
+        description: "用 data[i] 对 64K 元素数组求和 8192 遍，保留边界检查",
+        n: bounds::PASSES,
+        ops_per_n: bounds::LEN as u64,
+        work: Some(ELEM_PER_PASS),
+        reference: Some(0x0000_0000_aae4_d701),
+        baseline: Some(Baseline {
+            id: "T5_BOUNDS_UNCHECKED",
+            warn_above: None,
+        }),
+        buffer_bytes: bounds::BUFFER_BYTES,
+        prepare: Some(bounds::prepare),
+        reset: None,
+        needs_render: false,
+        run: bounds::indexed,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T5_BOUNDS_UNCHECKED",
+        description: "同一求和改用 get_unchecked，作为另外两种写法的基准",
+        n: bounds::PASSES,
+        ops_per_n: bounds::LEN as u64,
+        work: Some(ELEM_PER_PASS),
+        reference: Some(0x0000_0000_aae4_d701),
+        baseline: None,
+        buffer_bytes: bounds::BUFFER_BYTES,
+        prepare: Some(bounds::prepare),
+        reset: None,
+        needs_render: false,
+        run: bounds::unchecked,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T5_BOUNDS_ITER",
+        description: "同一求和改用切片迭代器",
+        n: bounds::PASSES,
+        ops_per_n: bounds::LEN as u64,
+        work: Some(ELEM_PER_PASS),
+        reference: Some(0x0000_0000_aae4_d701),
+        baseline: Some(Baseline {
+            id: "T5_BOUNDS_UNCHECKED",
+            warn_above: None,
+        }),
+        buffer_bytes: bounds::BUFFER_BYTES,
+        prepare: Some(bounds::prepare),
+        reset: None,
+        needs_render: false,
+        run: bounds::iter,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T6_CHACHA20",
+        description: "ChaCha20 加密 64 KiB 数据 256 遍，衡量 32 位 ARX 运算吞吐",
+        n: cipher::PASSES,
+        ops_per_n: (cipher::BYTES / 64) as u64,
+        work: Some(Work {
+            per_n: cipher::BYTES as f64,
+            scale: 1e6,
+            unit: "MB/s",
+        }),
+        reference: Some(0x0363_97d2_3ad8_fe4b),
+        baseline: None,
+        buffer_bytes: cipher::BUFFER_BYTES,
+        prepare: Some(cipher::prepare),
+        reset: Some(cipher::reset),
+        needs_render: false,
+        run: cipher::chacha20,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T7_SUM_NAIVE",
+        description: "直接累加 128K 个 f64，共 256 遍，作为 Kahan 求和的对照",
+        n: summation::PASSES,
+        ops_per_n: summation::LEN as u64,
+        work: None,
+        reference: Some(0x4217_d785_0598_1120),
+        baseline: None,
+        buffer_bytes: summation::BUFFER_BYTES,
+        prepare: Some(summation::prepare),
+        reset: None,
+        needs_render: false,
+        run: summation::naive,
+        finalize: None,
+    },
+    // 与朴素求和的耗时比即补偿求和的代价
+    CaseDef {
+        id: "T7_SUM_KAHAN",
+        description: "Kahan 补偿求和，精度更高但每个元素多 3 次浮点运算",
+        n: summation::PASSES,
+        ops_per_n: summation::LEN as u64,
+        work: None,
+        reference: Some(0x4217_d785_0598_0635),
+        baseline: Some(Baseline {
+            id: "T7_SUM_NAIVE",
+            warn_above: None,
+        }),
+        buffer_bytes: summation::BUFFER_BYTES,
+        prepare: Some(summation::prepare),
+        reset: None,
+        needs_render: false,
+        run: summation::kahan,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T8_FP_NORMAL",
+        description: "正常量级浮点数上的乘法链，100 万次迭代，每次 16 次运算",
+        n: denormal::ITERS,
+        ops_per_n: 16,
+        work: None,
+        reference: Some(0x084f_3851_7c88_8eaa),
+        baseline: None,
+        buffer_bytes: 0,
+        prepare: None,
+        reset: None,
+        needs_render: false,
+        run: denormal::normal,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T8_FP_SUBNORMAL",
+        description: "同一乘法链改在次正规量级上运行，部分硬件会因此明显变慢",
+        n: denormal::ITERS,
+        ops_per_n: 16,
+        work: None,
+        reference: Some(0x721d_1144_c46b_06f8),
+        baseline: Some(Baseline {
+            id: "T8_FP_NORMAL",
+            warn_above: Some(4.0),
+        }),
+        buffer_bytes: 0,
+        prepare: None,
+        reset: None,
+        needs_render: false,
+        run: denormal::subnormal,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T9_I128_MIX",
+        description: "2000 万次 128 位整数乘法、加法与移位，wasm32 上由编译器软件展开",
+        n: wide::ITERS,
+        ops_per_n: 1,
+        work: None,
+        reference: Some(0x6ebb_07e7_e5c2_d447),
+        baseline: None,
+        buffer_bytes: 0,
+        prepare: None,
+        reset: None,
+        needs_render: false,
+        run: wide::i128_mix,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T10_VEC_OPS",
+        description: "256K 元素 Vec 的扩容、迭代器适配器与 retain，共 32 轮",
+        n: containers::ROUNDS,
+        ops_per_n: containers::LEN as u64,
+        work: None,
+        reference: Some(0x5e0b_0dc6_1b58_73b6),
+        baseline: None,
+        buffer_bytes: containers::BUFFER_BYTES,
+        prepare: None,
+        reset: None,
+        needs_render: false,
+        run: containers::vec_ops,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T11_BTREE_MAP",
+        description: "向 BTreeMap 插入 64K 个随机键并做区间扫描，共 8 轮",
+        n: ordered_map::ROUNDS,
+        ops_per_n: ordered_map::KEYS as u64,
+        work: None,
+        reference: Some(0x70f6_e4b8_1d79_77ae),
+        baseline: None,
+        buffer_bytes: ordered_map::BUFFER_BYTES,
+        prepare: None,
+        reset: None,
+        needs_render: false,
+        run: ordered_map::btree,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T12_TEXT_FORMAT",
+        description: "用 write! 格式化 50 万条整数与浮点数，衡量字符串拼接开销",
+        n: text::ITEMS,
+        ops_per_n: 1,
+        work: None,
+        reference: Some(0x0000_0000_00a6_e095),
+        baseline: None,
+        buffer_bytes: 0,
+        prepare: None,
+        reset: None,
+        needs_render: false,
+        run: text::format,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T13_CRC32",
+        description: "对 1 MiB 数据做 16 遍查表 CRC32",
+        n: checksum::PASSES,
+        ops_per_n: checksum::BYTES as u64,
+        work: Some(BYTES_PER_PASS),
+        reference: Some(0x0000_0000_5cc3_3204),
+        baseline: None,
+        buffer_bytes: checksum::BUFFER_BYTES,
+        prepare: Some(checksum::prepare),
+        reset: None,
+        needs_render: false,
+        run: checksum::crc32,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T13_ADLER32",
+        description: "对同一数据做 Adler-32，与 CRC32 对比耗时",
+        n: checksum::PASSES,
+        ops_per_n: checksum::BYTES as u64,
+        work: Some(BYTES_PER_PASS),
+        reference: Some(0x0000_0000_3cf1_c860),
+        baseline: Some(Baseline {
+            id: "T13_CRC32",
+            warn_above: None,
+        }),
+        buffer_bytes: checksum::BUFFER_BYTES,
+        prepare: Some(checksum::prepare),
+        reset: None,
+        needs_render: false,
+        run: checksum::adler32,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T14_IMAGE_BLUR",
+        description: "320×320 RGBA 图像的 5 抽头可分离模糊 128 遍，按 MP/s 计",
+        n: image::PASSES,
+        ops_per_n: (image::WIDTH * image::HEIGHT) as u64,
+        work: Some(Work {
+            per_n: (image::WIDTH * image::HEIGHT) as f64,
+            scale: 1e6,
+            unit: "MP/s",
+        }),
+        reference: Some(0xdb08_99e0_9220_c325),
+        baseline: None,
+        buffer_bytes: image::BUFFER_BYTES,
+        prepare: Some(image::prepare),
+        reset: Some(image::reset),
+        needs_render: false,
+        run: image::gaussian_blur,
+        finalize: Some(image::finalize),
+    },
+    CaseDef {
+        id: "T15_AUDIO_BIQUAD",
+        description: "48000 个 f32 采样通过双二阶滤波器 64 遍，衡量逐样本依赖的单精度流水线",
+        n: audio::PASSES,
+        ops_per_n: audio::SAMPLES as u64,
+        work: Some(Work {
+            per_n: audio::SAMPLES as f64,
+            scale: 1e6,
+            unit: "M samples/s",
+        }),
+        reference: Some(0x0000_0000_bdc0_b971),
+        baseline: None,
+        buffer_bytes: audio::BUFFER_BYTES,
+        prepare: Some(audio::prepare),
+        reset: None,
+        needs_render: false,
+        run: audio::biquad_chain,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T16_DOT_F64",
+        description: "16K 元素的 f64 点积 2048 遍，作为定点实现的对照",
+        n: fixed::PASSES,
+        ops_per_n: fixed::LEN as u64,
+        work: None,
+        reference: Some(0xc0f6_6819_a4c5_d8a6),
+        baseline: None,
+        buffer_bytes: fixed::BUFFER_BYTES,
+        prepare: Some(fixed::prepare),
+        reset: None,
+        needs_render: false,
+        run: fixed::f64_dot,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T16_DOT_Q16",
+        description: "同一点积改用 Q16.16 定点整数运算",
+        n: fixed::PASSES,
+        ops_per_n: fixed::LEN as u64,
+        work: None,
+        reference: Some(0xffff_fffe_987d_6000),
+        baseline: Some(Baseline {
+            id: "T16_DOT_F64",
+            warn_above: None,
+        }),
+        buffer_bytes: fixed::BUFFER_BYTES,
+        prepare: Some(fixed::prepare),
+        reset: None,
+        needs_render: false,
+        run: fixed::q16,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T17_UI_RENDER",
+        description: "向宿主提交 64 次 1–128 个元素的界面，衡量 UI 桥往返，需要渲染目标",
+        n: host_ui::RENDERS,
+        ops_per_n: 1,
+        work: Some(Work {
+            per_n: host_ui::MEAN_TREE_SIZE,
+            scale: 1.0,
+            unit: "elem/s",
+        }),
+        reference: None,
+        baseline: None,
+        buffer_bytes: 0,
+        prepare: None,
+        reset: None,
+        needs_render: true,
+        run: host_ui::render,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T18_STORAGE_WRITE",
+        description: "向数据目录写入 4 KiB、64 KiB、1 MiB 三个文件，共 8 轮，衡量写吞吐",
+        n: storage_io::ROUNDS,
+        ops_per_n: storage_io::BLOB_SIZES.len() as u64,
+        work: Some(IO_PER_ROUND),
+        reference: Some(0x6000_ff80_07fc_001b),
+        baseline: None,
+        buffer_bytes: storage_io::BUFFER_BYTES,
+        prepare: Some(storage_io::prepare),
+        reset: None,
+        needs_render: false,
+        run: storage_io::write,
+        finalize: None,
+    },
+    CaseDef {
+        id: "T18_STORAGE_READ",
+        description: "读回上述文件并校验内容，共 8 轮，衡量读吞吐",
+        n: storage_io::ROUNDS,
+        ops_per_n: storage_io::BLOB_SIZES.len() as u64,
+        work: Some(IO_PER_ROUND),
+        reference: Some(0x4d6b_860d_8754_8af4),
+        baseline: None,
+        buffer_bytes: storage_io::BUFFER_BYTES,
+        prepare: Some(storage_io::prepare_read),
+        reset: None,
+        needs_render: false,
+        run: storage_io::read,
+        finalize: None,
+    },
+];
+
+// 内置用例在前，外部插件登记的自定义用例按登记顺序排在后面
+pub fn all() -> Vec<&'static CaseDef> {
+    let mut cases: Vec<&'static CaseDef> = CASES.iter().collect();
+    cases.extend(custom::custom_cases());
+    cases
+}
+
+pub fn find(id: &str) -> Option<&'static CaseDef> {
+    CASES
+        .iter()
+        .find(|case| case.id == id)
+        .or_else(|| custom::find_custom(id))
+}
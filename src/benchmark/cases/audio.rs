@@ -0,0 +1,72 @@
+// T15：音频 DSP。4 级 biquad（转置 II 型）级联处理 PRNG 白噪声，
+// 每个样本都依赖上一个样本的状态，是延迟敏感的 f32 流水线
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+// 1 秒 48 kHz 单声道
+pub const SAMPLES: usize = 48_000;
+pub const PASSES: u64 = 64;
+pub const BUFFER_BYTES: usize = SAMPLES * size_of::<f32>();
+
+// RBJ cookbook 系数，已按 a0 归一化并写成字面量，避免不同平台 sin/cos 实现带来差异：
+// 80 Hz 高通、1 kHz +6 dB 峰值、4 kHz 低通、12 kHz 低通，Q = 0.7071
+const SECTIONS: [([f32; 3], [f32; 2]); 4] = [
+    (
+        [0.992_622_5, -1.985_244_9, 0.992_622_5],
+        [-1.985_190_5, 0.985_299_37],
+    ),
+    (
+        [1.061_043, -1.861_272_1, 0.816_289_9],
+        [-1.861_272_1, 0.877_332_9],
+    ),
+    (
+        [0.049_489_83, 0.098_979_66, 0.049_489_83],
+        [-1.279_629_2, 0.477_588_55],
+    ),
+    (
+        [0.292_892_06, 0.585_784_1, 0.292_892_06],
+        [0.0, 0.171_568_22],
+    ),
+];
+
+pub fn fill_samples(seed: u32) -> Vec<f32> {
+    let mut rng = XorShift32::new(seed ^ 0xA0D1_0B1C);
+    (0..SAMPLES)
+        .map(|_| (rng.next_f64_01() * 2.0 - 1.0) as f32)
+        .collect()
+}
+
+pub fn prepare(workspace: &mut Workspace, seed: u32) {
+    workspace.samples(seed);
+}
+
+#[inline(never)]
+pub fn biquad_chain(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let input = ctx.workspace.samples(ctx.seed);
+    // 每级的 (z1, z2)，跨遍保留
+    let mut state = [[0f32; 2]; SECTIONS.len()];
+    let mut last: f32 = 0.0;
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for &x in std::hint::black_box(input) {
+                    let mut v = x;
+                    for (([b0, b1, b2], [a1, a2]), z) in SECTIONS.iter().zip(state.iter_mut()) {
+                        let y = b0 * v + z[0];
+                        z[0] = b1 * v - a1 * y + z[1];
+                        z[1] = b2 * v - a2 * y;
+                        v = y;
+                    }
+                    last = v;
+                }
+            }
+        },
+    );
+
+    ctx.barrier.sink(last.to_bits() as u64)
+}
@@ -0,0 +1,82 @@
+// T5：边界检查开销。三种写法对同一个 u32 数组做逐元素求和，digest 必须一致，
+// 耗时比即为当前宿主上安全下标的代价
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+// 256 KiB，落在多数设备的 L2 内，避免测成内存带宽
+pub const LEN: usize = 1 << 16;
+pub const PASSES: u64 = 8192;
+pub const BUFFER_BYTES: usize = LEN * size_of::<u32>();
+
+pub fn fill_buffer(seed: u32) -> Vec<u32> {
+    let mut rng = XorShift32::new(seed ^ 0xB0B5_C4EC);
+    (0..LEN).map(|_| rng.next_u32()).collect()
+}
+
+pub fn prepare(workspace: &mut Workspace, seed: u32) {
+    workspace.words(seed);
+}
+
+// 三个内核共用同一套外层循环，只换内层求和
+#[inline(always)]
+fn run_with(ctx: &mut CaseRun, on_chunk: &mut OnChunk, sum: fn(&[u32]) -> u32) -> u64 {
+    let data = ctx.workspace.words(ctx.seed);
+    let mut acc: u32 = 0;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                acc = (acc ^ sum(std::hint::black_box(data))).wrapping_mul(0x9E37_79B1);
+                acc ^= acc >> 15;
+            }
+        },
+    );
+
+    ctx.barrier.sink(acc) as u64
+}
+
+// 循环上界经过 black_box，编译器无法证明下标在界内，每次访问都保留检查
+#[inline(never)]
+#[allow(clippy::needless_range_loop)] // 下标写法本身就是被测对象
+fn sum_indexed(data: &[u32]) -> u32 {
+    let len = std::hint::black_box(data.len());
+    let mut sum: u32 = 0;
+    for i in 0..len {
+        sum = sum.wrapping_add(data[i]);
+    }
+    sum
+}
+
+#[inline(never)]
+fn sum_unchecked(data: &[u32]) -> u32 {
+    let len = std::hint::black_box(data.len());
+    let mut sum: u32 = 0;
+    for i in 0..len {
+        // SAFETY: black_box 不改变值，len 等于 data.len()，i < len
+        sum = sum.wrapping_add(unsafe { *data.get_unchecked(i) });
+    }
+    sum
+}
+
+#[inline(never)]
+fn sum_iter(data: &[u32]) -> u32 {
+    data.iter().fold(0u32, |sum, &w| sum.wrapping_add(w))
+}
+
+#[inline(never)]
+pub fn indexed(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    run_with(ctx, on_chunk, sum_indexed)
+}
+
+#[inline(never)]
+pub fn unchecked(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    run_with(ctx, on_chunk, sum_unchecked)
+}
+
+#[inline(never)]
+pub fn iter(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    run_with(ctx, on_chunk, sum_iter)
+}
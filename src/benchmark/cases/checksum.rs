@@ -0,0 +1,88 @@
+// T13：字节流校验和。CRC32（查表）与 Adler-32 共用同一段 PRNG 字节，
+// 校验值跨遍连续累计，给出两种简单字节处理的吞吐
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const BYTES: usize = 1024 * 1024;
+pub const PASSES: u64 = 16;
+pub const BUFFER_BYTES: usize = BYTES;
+const ADLER_MOD: u32 = 65521;
+// 保证 b 在取模前不溢出 u32 的最大块长（zlib 同值）
+const ADLER_NMAX: usize = 5552;
+
+// IEEE 802.3 反射多项式
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+};
+
+pub fn fill_bytes(seed: u32) -> Vec<u8> {
+    let mut rng = XorShift32::new(seed ^ 0xC5C3_2AD1);
+    (0..BYTES).map(|_| (rng.next_u32() >> 24) as u8).collect()
+}
+
+pub fn prepare(workspace: &mut Workspace, seed: u32) {
+    workspace.bytes(seed);
+}
+
+#[inline(never)]
+pub fn crc32(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let data = ctx.workspace.bytes(ctx.seed);
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for &b in std::hint::black_box(data) {
+                    crc = CRC_TABLE[((crc ^ b as u32) & 0xFF) as usize] ^ (crc >> 8);
+                }
+            }
+        },
+    );
+
+    ctx.barrier.sink((crc ^ 0xFFFF_FFFF) as u64)
+}
+
+#[inline(never)]
+pub fn adler32(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let data = ctx.workspace.bytes(ctx.seed);
+    let (mut a, mut b): (u32, u32) = (1, 0);
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for block in std::hint::black_box(data).chunks(ADLER_NMAX) {
+                    for &byte in block {
+                        a += byte as u32;
+                        b += a;
+                    }
+                    a %= ADLER_MOD;
+                    b %= ADLER_MOD;
+                }
+            }
+        },
+    );
+
+    ctx.barrier.sink(((b << 16) | a) as u64)
+}
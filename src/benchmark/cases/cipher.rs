@@ -0,0 +1,93 @@
+// T6：ChaCha20 流加密。对一段 PRNG 明文原地反复加密，
+// 块计数器跨遍递增，最后一个 64 字节块折叠为 digest
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const BYTES: usize = 64 * 1024;
+pub const PASSES: u64 = 256;
+// 明文副本与工作缓冲区各一份
+pub const BUFFER_BYTES: usize = 2 * BYTES;
+const BLOCK_WORDS: usize = 16;
+
+pub fn fill_buffer(seed: u32) -> Vec<u32> {
+    let mut rng = XorShift32::new(seed ^ 0x5EED_C1F0);
+    (0..BYTES / 4).map(|_| rng.next_u32()).collect()
+}
+
+pub fn prepare(workspace: &mut Workspace, seed: u32) {
+    workspace.cipher(seed);
+}
+
+pub fn reset(workspace: &mut Workspace, seed: u32) {
+    workspace.restore_cipher(seed);
+}
+
+#[inline(always)]
+fn quarter_round(s: &mut [u32; BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    s[a] = s[a].wrapping_add(s[b]);
+    s[d] = (s[d] ^ s[a]).rotate_left(16);
+    s[c] = s[c].wrapping_add(s[d]);
+    s[b] = (s[b] ^ s[c]).rotate_left(12);
+    s[a] = s[a].wrapping_add(s[b]);
+    s[d] = (s[d] ^ s[a]).rotate_left(8);
+    s[c] = s[c].wrapping_add(s[d]);
+    s[b] = (s[b] ^ s[c]).rotate_left(7);
+}
+
+// RFC 8439 块函数，key/nonce 以小端字序给出
+fn block(key: &[u32; 8], counter: u32, nonce: &[u32; 3]) -> [u32; BLOCK_WORDS] {
+    let mut init = [0u32; BLOCK_WORDS];
+    init[..4].copy_from_slice(&[0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574]);
+    init[4..12].copy_from_slice(key);
+    init[12] = counter;
+    init[13..].copy_from_slice(nonce);
+
+    let mut s = init;
+    for _ in 0..10 {
+        quarter_round(&mut s, 0, 4, 8, 12);
+        quarter_round(&mut s, 1, 5, 9, 13);
+        quarter_round(&mut s, 2, 6, 10, 14);
+        quarter_round(&mut s, 3, 7, 11, 15);
+        quarter_round(&mut s, 0, 5, 10, 15);
+        quarter_round(&mut s, 1, 6, 11, 12);
+        quarter_round(&mut s, 2, 7, 8, 13);
+        quarter_round(&mut s, 3, 4, 9, 14);
+    }
+    for (word, init) in s.iter_mut().zip(init) {
+        *word = word.wrapping_add(init);
+    }
+    s
+}
+
+#[inline(never)]
+pub fn chacha20(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let mut rng = XorShift32::new(ctx.seed ^ 0xC4AC_4A20);
+    let key: [u32; 8] = std::array::from_fn(|_| rng.next_u32());
+    let nonce: [u32; 3] = std::array::from_fn(|_| rng.next_u32());
+    let buf = ctx.workspace.cipher(ctx.seed);
+    let mut counter: u32 = 0;
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for chunk in buf.chunks_exact_mut(BLOCK_WORDS) {
+                    let stream = block(&key, counter, &nonce);
+                    for (word, k) in chunk.iter_mut().zip(stream) {
+                        *word ^= k;
+                    }
+                    counter = counter.wrapping_add(1);
+                }
+            }
+        },
+    );
+
+    let last = &buf[buf.len() - BLOCK_WORDS..];
+    let digest = last.iter().fold(0xCBF2_9CE4_8422_2325u64, |acc, &w| {
+        (acc ^ w as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    });
+    ctx.barrier.sink(digest)
+}
@@ -0,0 +1,55 @@
+use super::{CaseRun, OnChunk, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+// T1：INT32 混洗归约
+#[inline(never)]
+pub fn int32_mix(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let mut rng = XorShift32::new(ctx.seed);
+    let mut acc: u32 = 0x1234_5678;
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for i in start..end {
+                let x = rng.next_u32();
+                let mut v = x ^ acc;
+                v = v.rotate_left((i as u32) & 31);
+                v = v.wrapping_mul(0x9E37_79B1);
+                v ^= v >> 16;
+                acc = acc.wrapping_add(v);
+                if (v & 0x8000) != 0 {
+                    acc ^= 0xA5A5_A5A5;
+                }
+            }
+        },
+    );
+
+    ctx.barrier.sink(acc) as u64
+}
+
+// T2：FP64 流式点积
+#[inline(never)]
+pub fn fp64_dot(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let mut rng = XorShift32::new(ctx.seed ^ 0xDEAD_BEEF);
+    let mut sum: f64 = 0.0;
+    let c: f64 = 1e-9;
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                let a = rng.next_f64_01();
+                let b = rng.next_f64_01();
+                sum += a * b + c;
+            }
+        },
+    );
+
+    ctx.barrier.sink(sum.to_bits())
+}
@@ -0,0 +1,47 @@
+// T10：惯用容器写法。每轮新建 Vec 逐个 push，再走一遍迭代器 map/filter 归约，
+// 最后 retain 过滤，考察的是标准库容器与迭代器适配器而不是手写循环
+use super::{CaseRun, OnChunk, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const LEN: usize = 1 << 18;
+pub const ROUNDS: u64 = 32;
+// 扩容时新旧两块同时存在
+pub const BUFFER_BYTES: usize = 2 * LEN * size_of::<u32>();
+
+#[inline(never)]
+pub fn vec_ops(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let mut rng = XorShift32::new(ctx.seed ^ 0x7EC0_0B5E);
+    let mut acc: u64 = 0;
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                // 不预留容量，保留扩容路径
+                let mut values = Vec::new();
+                for _ in 0..LEN {
+                    values.push(rng.next_u32());
+                }
+
+                let mapped: u64 = values
+                    .iter()
+                    .map(|&v| (v >> 3) as u64)
+                    .filter(|v| v & 1 == 0)
+                    .sum();
+
+                values.retain(|&v| v % 3 != 0);
+                let survivors = values
+                    .iter()
+                    .fold(0u64, |sum, &v| sum.wrapping_add(v as u64));
+
+                acc = acc.rotate_left(13) ^ mapped ^ survivors.wrapping_mul(values.len() as u64);
+                std::hint::black_box(values);
+            }
+        },
+    );
+
+    ctx.barrier.sink(acc)
+}
@@ -0,0 +1,46 @@
+// T8：次正规数探测。同一段乘法链分别在正常量级和次正规量级上运行，
+// 部分硬件（或软件浮点实现）处理次正规数会慢上几十倍
+use super::{CaseRun, OnChunk, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const ITERS: u64 = 1_000_000;
+const LANES: usize = 8;
+// 乘以 0.75 再乘回 4/3，数值在原量级附近游走，既不下溢也不回到正常范围
+const DOWN: f64 = 0.75;
+const UP: f64 = 4.0 / 3.0;
+
+#[inline(always)]
+fn run_at(ctx: &mut CaseRun, on_chunk: &mut OnChunk, base: f64) -> u64 {
+    let mut rng = XorShift32::new(ctx.seed ^ 0xDE40_4A11);
+    let mut lanes: [f64; LANES] = std::array::from_fn(|_| base * (1.0 + rng.next_f64_01()));
+    let (down, up) = std::hint::black_box((DOWN, UP));
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for v in lanes.iter_mut() {
+                    *v = (*v * down) * up;
+                }
+            }
+        },
+    );
+
+    let digest = lanes
+        .iter()
+        .fold(0u64, |acc, v| acc.rotate_left(9) ^ v.to_bits());
+    ctx.barrier.sink(digest)
+}
+
+#[inline(never)]
+pub fn normal(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    run_at(ctx, on_chunk, 1.0)
+}
+
+#[inline(never)]
+pub fn subnormal(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    run_at(ctx, on_chunk, 1e-310)
+}
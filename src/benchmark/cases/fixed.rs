@@ -0,0 +1,65 @@
+// T16：定点与浮点对比。同一组 [-1, 1) 输入分别以 Q16.16 定点和 f64 做点积，
+// 定点结果附带相对 f64 的耗时比，供没有高效 FPU 的目标参考
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const LEN: usize = 1 << 14;
+pub const PASSES: u64 = 2048;
+pub const BUFFER_BYTES: usize = LEN * (size_of::<i32>() + size_of::<f64>());
+const ONE: f64 = 65536.0;
+
+// 交错存放 a0 b0 a1 b1 ...，定点值由同一个浮点值截断得到
+pub fn fill_inputs(seed: u32) -> (Vec<i32>, Vec<f64>) {
+    let mut rng = XorShift32::new(seed ^ 0x0F16_D07F);
+    let float: Vec<f64> = (0..LEN * 2)
+        .map(|_| rng.next_f64_01() * 2.0 - 1.0)
+        .collect();
+    let fixed = float.iter().map(|&v| (v * ONE) as i32).collect();
+    (fixed, float)
+}
+
+pub fn prepare(workspace: &mut Workspace, seed: u32) {
+    workspace.dot_inputs(seed);
+}
+
+#[inline(never)]
+pub fn q16(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let (fixed, _) = ctx.workspace.dot_inputs(ctx.seed);
+    let mut acc: i64 = 0;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for pair in std::hint::black_box(fixed).chunks_exact(2) {
+                    acc = acc.wrapping_add((pair[0] as i64 * pair[1] as i64) >> 16);
+                }
+            }
+        },
+    );
+
+    ctx.barrier.sink(acc as u64)
+}
+
+#[inline(never)]
+pub fn f64_dot(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let (_, float) = ctx.workspace.dot_inputs(ctx.seed);
+    let mut acc: f64 = 0.0;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for pair in std::hint::black_box(float).chunks_exact(2) {
+                    acc += pair[0] * pair[1];
+                }
+            }
+        },
+    );
+
+    ctx.barrier.sink(acc.to_bits())
+}
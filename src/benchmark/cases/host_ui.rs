@@ -0,0 +1,40 @@
+// T17：UI 性能。经 Host::render_tree 向宿主连续提交大小不一的元素树，测量渲染往返耗时，
+// 考察的是宿主 UI 桥而不是 CPU。测试期间界面会被测试树短暂替换
+use super::{CaseRun, OnChunk, for_each_chunk};
+
+pub const RENDERS: u64 = 64;
+// 每次渲染的 P 元素个数，按顺序循环
+pub const TREE_SIZES: [u32; 4] = [1, 8, 32, 128];
+
+pub const MEAN_TREE_SIZE: f64 = {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < TREE_SIZES.len() {
+        sum += TREE_SIZES[i];
+        i += 1;
+    }
+    sum as f64 / TREE_SIZES.len() as f64
+};
+
+#[inline(never)]
+pub fn render(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let Some(target) = ctx.render_target else {
+        return 0;
+    };
+    let mut elements: u64 = 0;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for round in start..end {
+                let size = TREE_SIZES[round as usize % TREE_SIZES.len()];
+                ctx.host.render_tree(target, size, round);
+                elements += size as u64;
+            }
+        },
+    );
+
+    ctx.barrier.sink(elements)
+}
@@ -0,0 +1,97 @@
+// T14：图像模糊。对一张合成 RGBA 图做可分离 5×5 高斯模糊（核 1 4 6 4 1），
+// 整数运算、边缘取钳位，每遍在上一遍的输出上继续模糊
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const WIDTH: usize = 320;
+pub const HEIGHT: usize = 320;
+pub const PASSES: u64 = 128;
+const CHANNELS: usize = 4;
+// 原图、工作图与 u16 中间结果
+pub const BUFFER_BYTES: usize = WIDTH * HEIGHT * CHANNELS * (2 + size_of::<u16>());
+const KERNEL: [u32; 5] = [1, 4, 6, 4, 1];
+
+// 横向渐变、纵向渐变、噪声、不透明
+pub fn fill_image(seed: u32) -> Vec<u8> {
+    let mut rng = XorShift32::new(seed ^ 0x1A6E_B10B);
+    let mut img = Vec::with_capacity(WIDTH * HEIGHT * CHANNELS);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            img.push((x * 255 / (WIDTH - 1)) as u8);
+            img.push((y * 255 / (HEIGHT - 1)) as u8);
+            img.push((rng.next_u32() >> 24) as u8);
+            img.push(255);
+        }
+    }
+    img
+}
+
+pub fn prepare(workspace: &mut Workspace, seed: u32) {
+    workspace.image(seed);
+}
+
+pub fn reset(workspace: &mut Workspace, seed: u32) {
+    workspace.restore_image(seed);
+}
+
+#[inline(always)]
+fn clamp_at(i: usize, offset: usize, len: usize) -> usize {
+    (i + offset).saturating_sub(2).min(len - 1)
+}
+
+fn blur_pass(img: &mut [u8], tmp: &mut [u16]) {
+    // 横向：u8 -> u16，最大 255 * 16
+    for y in 0..HEIGHT {
+        let row = &img[y * WIDTH * CHANNELS..(y + 1) * WIDTH * CHANNELS];
+        let out = &mut tmp[y * WIDTH * CHANNELS..(y + 1) * WIDTH * CHANNELS];
+        for x in 0..WIDTH {
+            for c in 0..CHANNELS {
+                let mut sum = 0u32;
+                for (k, w) in KERNEL.iter().enumerate() {
+                    sum += w * row[clamp_at(x, k, WIDTH) * CHANNELS + c] as u32;
+                }
+                out[x * CHANNELS + c] = sum as u16;
+            }
+        }
+    }
+    // 纵向：u16 -> u8，两次各除 16，四舍五入
+    let stride = WIDTH * CHANNELS;
+    for y in 0..HEIGHT {
+        for i in 0..stride {
+            let mut sum = 0u32;
+            for (k, w) in KERNEL.iter().enumerate() {
+                sum += w * tmp[clamp_at(y, k, HEIGHT) * stride + i] as u32;
+            }
+            img[y * stride + i] = ((sum + 128) >> 8) as u8;
+        }
+    }
+}
+
+#[inline(never)]
+pub fn gaussian_blur(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let (img, tmp) = ctx.workspace.image(ctx.seed);
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                blur_pass(std::hint::black_box(&mut *img), tmp);
+            }
+        },
+    );
+
+    ctx.barrier.sink(img[0] as u64)
+}
+
+// digest 是模糊结果逐像素的 FNV-1a，计时结束后再算
+pub fn finalize(workspace: &Workspace, _raw: u64) -> u64 {
+    workspace
+        .image_out()
+        .chunks_exact(CHANNELS)
+        .fold(0xCBF2_9CE4_8422_2325u64, |acc, px| {
+            let word = u32::from_le_bytes([px[0], px[1], px[2], px[3]]);
+            (acc ^ word as u64).wrapping_mul(0x0000_0100_0000_01B3)
+        })
+}
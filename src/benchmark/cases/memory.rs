@@ -0,0 +1,104 @@
+// -------- Memory bandwidth (read / write / copy measured separately) --------
+// n 为遍数，每遍完整扫过一次缓冲区，块边界总落在两遍之间。每遍的读写都经 ctx.barrier，
+// 屏障设置对这三个用例同样生效
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const MEM_BYTES: usize = crate::benchmark::BENCH_MEM_BYTES;
+pub const MEM_WORDS: u64 = (MEM_BYTES / 8) as u64;
+pub const MEM_PASSES: u64 = crate::benchmark::BENCH_MEM_PASSES;
+// 源与目标各一份
+pub const BUFFER_BYTES: usize = 2 * MEM_BYTES;
+const SAMPLE_STRIDE: usize = 4096;
+
+pub fn fill_buffer(seed: u32, bytes: usize) -> Vec<u64> {
+    let mut rng = XorShift32::new(seed ^ 0x5EED_F00D);
+    (0..bytes / 8)
+        .map(|_| ((rng.next_u32() as u64) << 32) | rng.next_u32() as u64)
+        .collect()
+}
+
+fn sample_digest(buf: &[u64]) -> u64 {
+    buf.iter()
+        .step_by(SAMPLE_STRIDE)
+        .fold(0xCBF2_9CE4_8422_2325, |acc, &w| {
+            (acc ^ w).wrapping_mul(0x0000_0100_0000_01B3)
+        })
+}
+
+pub fn prepare(workspace: &mut Workspace, seed: u32) {
+    workspace.mem(seed);
+}
+
+#[inline(never)]
+pub fn read(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let barrier = ctx.barrier;
+    let (buf, _) = ctx.workspace.mem(ctx.seed);
+    let mut acc: u64 = 0;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                let mut sum: u64 = 0;
+                for &w in barrier.sink(buf) {
+                    sum = sum.wrapping_add(w);
+                }
+                acc = acc.rotate_left(7) ^ sum;
+            }
+        },
+    );
+
+    barrier.sink(acc)
+}
+
+#[inline(never)]
+pub fn write(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let barrier = ctx.barrier;
+    let (_, buf) = ctx.workspace.mem(ctx.seed);
+    let seed = ctx.seed as u64;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for pass in start..end {
+                let v = (seed << 32 | pass).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+                for (i, w) in buf.iter_mut().enumerate() {
+                    *w = v ^ i as u64;
+                }
+                barrier.publish(&mut *buf);
+            }
+        },
+    );
+
+    barrier.sink(buf[0])
+}
+
+#[inline(never)]
+pub fn copy(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let barrier = ctx.barrier;
+    let (src, dst) = ctx.workspace.mem(ctx.seed);
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                dst.copy_from_slice(barrier.sink(src));
+                barrier.publish(&mut *dst);
+            }
+        },
+    );
+
+    barrier.sink(dst[0])
+}
+
+// 写与复制共用：对目标缓冲区抽样折叠
+pub fn finalize(workspace: &Workspace, _raw: u64) -> u64 {
+    sample_digest(workspace.mem_out())
+}
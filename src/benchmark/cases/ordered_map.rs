@@ -0,0 +1,46 @@
+// T11：BTreeMap 有序映射。随机键插入后做区间扫描，节点分散在堆上，
+// 考察指针跳转与分支密集的访问模式
+use std::collections::BTreeMap;
+
+use super::{CaseRun, OnChunk, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const KEYS: usize = 1 << 16;
+pub const ROUNDS: u64 = 8;
+// 按每项约 32 字节（键、值与节点开销）粗估
+pub const BUFFER_BYTES: usize = KEYS * 32;
+const SCANS: usize = 4096;
+// 键均匀分布在 u32 上，每次扫描平均命中 KEYS * SPAN / 2^32 = 16 项
+const SPAN: u32 = 1 << 20;
+
+#[inline(never)]
+pub fn btree(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let mut rng = XorShift32::new(ctx.seed ^ 0xB7EE_3A90);
+    let mut acc: u64 = 0;
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                let mut map = BTreeMap::new();
+                for i in 0..KEYS as u32 {
+                    map.insert(rng.next_u32(), i);
+                }
+
+                let mut sum: u64 = 0;
+                for _ in 0..SCANS {
+                    let from = rng.next_u32();
+                    for (&k, &v) in map.range(from..from.saturating_add(SPAN)) {
+                        sum = sum.wrapping_add(k as u64 ^ v as u64);
+                    }
+                }
+                acc = acc.rotate_left(11) ^ sum ^ map.len() as u64;
+            }
+        },
+    );
+
+    ctx.barrier.sink(acc)
+}
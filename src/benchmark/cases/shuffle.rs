@@ -0,0 +1,50 @@
+// T4：Fisher–Yates 洗牌。每次迭代从恒等排列开始，按 n 轮连续洗牌，
+// 同时考察 PRNG 消耗、随机写入和带边界检查的下标访问
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const LEN: usize = 1 << 20;
+pub const ROUNDS: u64 = 32;
+pub const BUFFER_BYTES: usize = LEN * size_of::<u32>();
+
+pub fn prepare(workspace: &mut Workspace, _seed: u32) {
+    workspace.indices(LEN);
+}
+
+// 每次迭代从恒等排列开始
+pub fn reset(workspace: &mut Workspace, _seed: u32) {
+    workspace.reset_indices(LEN);
+}
+
+#[inline(never)]
+pub fn run(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let mut rng = XorShift32::new(ctx.seed ^ 0x5348_5546);
+    let idx = ctx.workspace.indices(LEN);
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for i in (1..idx.len()).rev() {
+                    let j = (rng.next_u32() % (i as u32 + 1)) as usize;
+                    idx.swap(i, j);
+                }
+            }
+        },
+    );
+
+    ctx.barrier.sink(idx[0] as u64)
+}
+
+// digest 是整个排列的 FNV-1a，计时结束后再算
+pub fn finalize(workspace: &Workspace, _raw: u64) -> u64 {
+    workspace
+        .indices_out()
+        .iter()
+        .fold(0xCBF2_9CE4_8422_2325u64, |acc, &v| {
+            (acc ^ v as u64).wrapping_mul(0x0000_0100_0000_01B3)
+        })
+}
@@ -0,0 +1,112 @@
+// T18：存储 I/O。把几种大小的确定性数据块写入插件数据目录再读回，
+// 分别测写入与读取吞吐，读回内容与原数据按 FNV 比对。
+// 不调用 fsync，测到的是插件可见的文件接口速度，可能命中宿主缓存
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+use crate::storage;
+
+pub const BLOB_SIZES: [usize; 3] = [4 * 1024, 64 * 1024, 1024 * 1024];
+pub const ROUNDS: u64 = 8;
+pub const BYTES_PER_ROUND: usize = BLOB_SIZES[0] + BLOB_SIZES[1] + BLOB_SIZES[2];
+// 数据块本身，外加读回用的同等大小缓冲
+pub const BUFFER_BYTES: usize = 2 * BYTES_PER_ROUND;
+
+pub fn fill_blobs(seed: u32) -> Vec<Vec<u8>> {
+    let mut rng = XorShift32::new(seed ^ 0x0B10_B5ED);
+    BLOB_SIZES
+        .iter()
+        .map(|&size| (0..size).map(|_| (rng.next_u32() >> 24) as u8).collect())
+        .collect()
+}
+
+fn blob_name(size: usize) -> String {
+    format!("bench_io/blob_{}.bin", size)
+}
+
+fn fnv(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF2_9CE4_8422_2325, |acc, &b| {
+        (acc ^ b as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+pub fn blob_digests(blobs: &[Vec<u8>]) -> Vec<u64> {
+    blobs.iter().map(|blob| fnv(blob)).collect()
+}
+
+pub fn prepare(workspace: &mut Workspace, seed: u32) {
+    workspace.blobs(seed);
+}
+
+// 读取用例不依赖写入用例是否执行过，先自行落盘一份；失败留给计时阶段报告。
+// 比对用的 FNV 也在这里算好，不计入读取耗时
+pub fn prepare_read(workspace: &mut Workspace, seed: u32) {
+    for blob in workspace.blobs(seed) {
+        let _ = storage::write(&blob_name(blob.len()), blob);
+    }
+    workspace.blob_digests(seed);
+}
+
+#[inline(never)]
+pub fn write(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let blobs = ctx.workspace.blobs(ctx.seed);
+    let mut error = None;
+    let mut digest: u64 = 0;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for blob in blobs.iter() {
+                    if error.is_some() {
+                        return;
+                    }
+                    match storage::write(&blob_name(blob.len()), blob) {
+                        Ok(()) => digest = digest.rotate_left(7) ^ blob.len() as u64,
+                        Err(err) => error = Some(format!("写入失败: {}", err)),
+                    }
+                }
+            }
+        },
+    );
+    if let Some(error) = error {
+        ctx.fail(error);
+    }
+
+    ctx.barrier.sink(digest)
+}
+
+#[inline(never)]
+pub fn read(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let expected = ctx.workspace.blob_digests(ctx.seed);
+    let mut error = None;
+    let mut digest: u64 = 0;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for (&size, &want) in BLOB_SIZES.iter().zip(expected) {
+                    if error.is_some() {
+                        return;
+                    }
+                    match storage::read(&blob_name(size)) {
+                        Ok(bytes) if fnv(&bytes) == want => digest = digest.rotate_left(7) ^ want,
+                        Ok(bytes) => {
+                            error = Some(format!("读回内容不符 ({} 字节)", bytes.len()));
+                        }
+                        Err(err) => error = Some(format!("读取失败: {}", err)),
+                    }
+                }
+            }
+        },
+    );
+    if let Some(error) = error {
+        ctx.fail(error);
+    }
+
+    ctx.barrier.sink(digest)
+}
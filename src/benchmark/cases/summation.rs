@@ -0,0 +1,72 @@
+// T7：病态级数求和。每遍开头一个 1e8 的大项，其后全是 1e-3 量级的小项，
+// 累加和越大小项丢失的位越多。朴素求和与 Kahan 补偿求和给出不同的位模式，
+// 两者都有参考 digest，宿主的浮点行为不标准（如 FMA 收缩、扩展精度）时会校验失败
+use super::{CaseRun, OnChunk, Workspace, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const LEN: usize = 1 << 17;
+pub const PASSES: u64 = 256;
+pub const BUFFER_BYTES: usize = LEN * size_of::<f64>();
+
+pub fn fill_series(seed: u32) -> Vec<f64> {
+    let mut rng = XorShift32::new(seed ^ 0x4B41_4841);
+    (0..LEN)
+        .map(|i| {
+            if i == 0 {
+                1e8
+            } else {
+                rng.next_f64_01() * 1e-3
+            }
+        })
+        .collect()
+}
+
+pub fn prepare(workspace: &mut Workspace, seed: u32) {
+    workspace.series(seed);
+}
+
+#[inline(never)]
+pub fn naive(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let series = ctx.workspace.series(ctx.seed);
+    let mut sum: f64 = 0.0;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for &x in std::hint::black_box(series) {
+                    sum += x;
+                }
+            }
+        },
+    );
+
+    ctx.barrier.sink(sum.to_bits())
+}
+
+#[inline(never)]
+pub fn kahan(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let series = ctx.workspace.series(ctx.seed);
+    let mut sum: f64 = 0.0;
+    let mut comp: f64 = 0.0;
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                for &x in std::hint::black_box(series) {
+                    let y = x - comp;
+                    let t = sum + y;
+                    comp = (t - sum) - y;
+                    sum = t;
+                }
+            }
+        },
+    );
+
+    ctx.barrier.sink(sum.to_bits())
+}
@@ -0,0 +1,38 @@
+// T12：文本格式化。用 write! 把整数和浮点数写进复用的 String，
+// 插件拼界面字符串时这部分开销经常是热点
+use std::fmt::Write;
+
+use super::{CaseRun, OnChunk, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const ITEMS: u64 = 500_000;
+// 每写满这么多条清空一次，缓冲区大小保持稳定
+const FLUSH_EVERY: u64 = 4096;
+
+#[inline(never)]
+pub fn format(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let mut rng = XorShift32::new(ctx.seed ^ 0x7E47_F0A7);
+    let mut buf = String::with_capacity(64 * FLUSH_EVERY as usize);
+    let mut total: u64 = 0;
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for i in start..end {
+                let int = rng.next_u32() as i32;
+                let float = rng.next_f64_01() * 1e6;
+                let _ = writeln!(buf, "{} {:.3}", int, float);
+                if (i + 1) % FLUSH_EVERY == 0 {
+                    total += std::hint::black_box(&buf).len() as u64;
+                    buf.clear();
+                }
+            }
+        },
+    );
+    total += buf.len() as u64;
+
+    ctx.barrier.sink(total)
+}
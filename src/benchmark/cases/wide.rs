@@ -0,0 +1,36 @@
+// T9：128 位整数乘加链。wasm32 等目标没有原生 128 位乘法，i128 运算由编译器展开，
+// 代价在普通整数用例里看不出来
+use super::{CaseRun, OnChunk, for_each_chunk};
+use crate::benchmark::prng::XorShift32;
+
+pub const ITERS: u64 = 20_000_000;
+// PCG64 的 128 位 LCG 乘数
+const MUL: u128 = 0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645;
+
+#[inline(never)]
+pub fn i128_mix(ctx: &mut CaseRun, on_chunk: &mut OnChunk) -> u64 {
+    let mut rng = XorShift32::new(ctx.seed ^ 0x0128_0128);
+    let mut state: u128 = ((rng.next_u32() as u128) << 96) | rng.next_u32() as u128 | 1;
+    // Q64.64 定点累加器，有符号
+    let mut acc: i128 = 0;
+
+    for_each_chunk(
+        ctx.n,
+        ctx.chunks,
+        ctx.chunk_stride,
+        on_chunk,
+        |start, end| {
+            for _ in start..end {
+                state = state.wrapping_mul(MUL).wrapping_add(0x9E37_79B9_7F4A_7C15);
+                let x = (state >> 64) as i64 as i128;
+                let y = (state as u64 >> 1) as i128;
+                // 定点乘法：(x * y) >> 64
+                acc = acc.wrapping_add(x.wrapping_mul(y) >> 64);
+                acc ^= acc >> 61;
+            }
+        },
+    );
+
+    let digest = (acc as u128 >> 64) as u64 ^ acc as u64 ^ (state >> 64) as u64;
+    ctx.barrier.sink(digest)
+}
@@ -0,0 +1,95 @@
+// 运行前后的条件快照与对比。psys_host 不提供电量、充电、温度与系统空闲内存，
+// 这里用空载噪声探测里固定循环的耗时作为降频/后台负载的替代指标，
+// 另记录插件自身的线性内存大小
+use serde_json::{Value, json};
+
+use super::{NoiseProbe, ms3};
+
+// 宿主接口缺失、无法采集的条件，原样写入 JSON 提醒读者
+pub const UNAVAILABLE: [&str; 4] = ["battery", "charger", "thermal", "free_memory"];
+// 固定循环的 p50 变化超过该比例视为条件明显不同
+pub const LOOP_CHANGE_LIMIT: f64 = 0.15;
+
+#[derive(Clone, Copy)]
+pub struct Conditions {
+    // 空载噪声探测中固定短循环的耗时；降频时整体变慢
+    pub loop_p50_us: f64,
+    pub noise_score: f64,
+    // 插件 wasm 线性内存大小，原生构建时为 None
+    pub memory_bytes: Option<u64>,
+}
+
+pub struct ConditionsDelta {
+    // other / base - 1
+    pub loop_change: f64,
+    pub noise_change: f64,
+    // 循环耗时变化超过 LOOP_CHANGE_LIMIT，或一边繁忙一边空闲
+    pub significant: bool,
+}
+
+#[cfg(target_arch = "wasm32")]
+fn memory_bytes() -> Option<u64> {
+    Some(core::arch::wasm32::memory_size(0) as u64 * 65_536)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn memory_bytes() -> Option<u64> {
+    None
+}
+
+fn is_busy(score: f64) -> bool {
+    score > NoiseProbe::BUSY_SCORE
+}
+
+impl Conditions {
+    pub fn from_probe(noise: &NoiseProbe) -> Self {
+        Conditions {
+            loop_p50_us: noise.p50_us,
+            noise_score: noise.score,
+            memory_bytes: memory_bytes(),
+        }
+    }
+
+    pub fn json(&self) -> Value {
+        json!({
+            "loop_p50_us": ms3(self.loop_p50_us),
+            "noise_score": ms3(self.noise_score),
+            "memory_bytes": self.memory_bytes,
+        })
+    }
+
+    pub fn from_json(value: &Value) -> Option<Self> {
+        Some(Conditions {
+            loop_p50_us: value.get("loop_p50_us")?.as_f64()?,
+            noise_score: value.get("noise_score")?.as_f64()?,
+            memory_bytes: value.get("memory_bytes").and_then(Value::as_u64),
+        })
+    }
+
+    pub fn compare(&self, other: &Conditions) -> ConditionsDelta {
+        let loop_change = if self.loop_p50_us > 0.0 {
+            other.loop_p50_us / self.loop_p50_us - 1.0
+        } else {
+            0.0
+        };
+        ConditionsDelta {
+            loop_change,
+            noise_change: other.noise_score - self.noise_score,
+            significant: loop_change.abs() > LOOP_CHANGE_LIMIT
+                || is_busy(self.noise_score) != is_busy(other.noise_score),
+        }
+    }
+}
+
+// JSON 的 conditions_delta 段
+pub fn delta_json(before: &Conditions, after: &Conditions) -> Value {
+    let delta = before.compare(after);
+    json!({
+        "before": before.json(),
+        "after": after.json(),
+        "loop_change": ms3(delta.loop_change),
+        "noise_change": ms3(delta.noise_change),
+        "significant": delta.significant,
+        "unavailable": UNAVAILABLE,
+    })
+}
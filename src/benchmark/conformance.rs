@@ -0,0 +1,131 @@
+// 跨语言一致性：在一组小规模 (seed, n) 上跑全部内核，把 digest 写成规范化的 JSON 基准，
+// 其他语言移植版产出同样格式的文件后可以逐条比对。需要宿主渲染的用例不参与
+use serde_json::{Value, json};
+
+use super::cases::{CaseRun, Workspace};
+use super::{BENCH_SEED, Barrier, CASES, CaseDef, MAX_CHUNKS, NoHost, hex};
+
+pub const FIXTURE_KIND: &str = "astrobox-benchmark-conformance";
+pub const FIXTURE_VERSION: u64 = 1;
+pub const SEEDS: [u32; 3] = [1, BENCH_SEED, 0xC0FF_EE00];
+// 相对默认规模的缩小倍数，n 至少为 1
+pub const SCALES: [u64; 2] = [1000, 100];
+
+pub struct EntryCheck {
+    pub id: String,
+    pub seed: u32,
+    pub n: u64,
+    pub expected: u64,
+    // 未知用例或内核出错时为 Err
+    pub actual: Result<u64, String>,
+}
+
+impl EntryCheck {
+    pub fn passed(&self) -> bool {
+        self.actual
+            .as_ref()
+            .is_ok_and(|&actual| actual == self.expected)
+    }
+}
+
+fn small_ns(case: &CaseDef) -> Vec<u64> {
+    let mut ns: Vec<u64> = SCALES.iter().map(|scale| (case.n / scale).max(1)).collect();
+    ns.dedup();
+    ns
+}
+
+// 每个 (用例, seed) 用新的 Workspace，缓冲区只按首次的 seed 生成
+fn run_case(case: &CaseDef, seed: u32, n: u64) -> Result<u64, String> {
+    let mut workspace = Workspace::default();
+    if let Some(prepare) = case.prepare {
+        prepare(&mut workspace, seed);
+    }
+    case.reset_workspace(&mut workspace, seed);
+    let mut run = CaseRun {
+        seed,
+        n,
+        barrier: Barrier::BlackBox,
+        chunks: MAX_CHUNKS,
+        chunk_stride: 1,
+        workspace: &mut workspace,
+        render_target: None,
+        host: &NoHost,
+        error: None,
+    };
+    let raw = (case.run)(&mut run, &mut |_, _| {});
+    match run.error.take() {
+        Some(message) => Err(message),
+        None => Ok(case.digest(&workspace, raw)),
+    }
+}
+
+// 条目按注册顺序、seed、n 排列，字段顺序固定
+pub fn generate_fixture() -> String {
+    let mut entries = Vec::new();
+    for case in CASES.iter().filter(|case| !case.needs_render) {
+        for seed in SEEDS {
+            for n in small_ns(case) {
+                let digest = match run_case(case, seed, n) {
+                    Ok(digest) => Value::from(hex(digest)),
+                    Err(message) => {
+                        tracing::error!("{} seed={} n={} failed: {}", case.id, seed, n, message);
+                        Value::Null
+                    }
+                };
+                entries.push(json!({ "id": case.id, "seed": seed, "n": n, "digest": digest }));
+            }
+        }
+    }
+    let fixture = json!({
+        "kind": FIXTURE_KIND,
+        "version": FIXTURE_VERSION,
+        "lang": "rust",
+        "entries": entries,
+    });
+    serde_json::to_string_pretty(&fixture).unwrap_or_default()
+}
+
+fn parse_entry(entry: &Value) -> Option<(String, u32, u64)> {
+    let id = entry.get("id")?.as_str()?.to_string();
+    let seed = u32::try_from(entry.get("seed")?.as_u64()?).ok()?;
+    let n = entry.get("n")?.as_u64()?;
+    Some((id, seed, n))
+}
+
+// 对基准文件中的每一条重新运行并比对，不要求与本地的矩阵一致
+pub fn check_fixture(text: &str) -> Result<Vec<EntryCheck>, String> {
+    let fixture: Value = serde_json::from_str(text).map_err(|err| format!("无法解析: {}", err))?;
+    if fixture.get("kind").and_then(Value::as_str) != Some(FIXTURE_KIND) {
+        return Err("不是一致性基准文件".to_string());
+    }
+    let entries = fixture
+        .get("entries")
+        .and_then(Value::as_array)
+        .ok_or("缺少 entries")?;
+
+    let mut checks = Vec::with_capacity(entries.len());
+    for (i, entry) in entries.iter().enumerate() {
+        let (id, seed, n) =
+            parse_entry(entry).ok_or_else(|| format!("第 {} 条格式不正确", i + 1))?;
+        // 生成方的内核出错时 digest 为 null
+        let expected = entry
+            .get("digest")
+            .and_then(Value::as_str)
+            .and_then(|digest| u64::from_str_radix(digest, 16).ok());
+        let actual = match (CASES.iter().find(|case| case.id == id), expected) {
+            (None, _) => Err("未知用例".to_string()),
+            (Some(case), _) if case.needs_render => Err("需要宿主渲染，不参与比对".to_string()),
+            (Some(_), None) => Err("基准中缺少 digest".to_string()),
+            (Some(case), Some(_)) => run_case(case, seed, n),
+        };
+        let expected = expected.unwrap_or(0);
+        checks.push(EntryCheck {
+            id,
+            seed,
+            n,
+            expected,
+            actual,
+        });
+    }
+    Ok(checks)
+}
@@ -0,0 +1,180 @@
+// 外部插件登记的自定义用例。插件之间不能互相调用代码，psys_host 也没有跨插件的可调用句柄，
+// 所以外部插件经宿主命令提交一份工作负载描述：选一个内置内核、给出自己的规模 n，
+// 由本插件的运行器执行计时，结果与内置用例一起出现在报告和界面里。
+// 登记只在本次会话内有效；CaseDef 需要 'static，每种不同的定义泄漏一小块内存，
+// 同一定义再次登记时复用已泄漏的那份，本次会话内泄漏的定义总数不超过 MAX_DEFINITIONS
+use std::sync::Mutex;
+
+use serde_json::{Value, json};
+
+use super::cases::{CASES, CaseDef};
+
+pub const MAX_CUSTOM_CASES: usize = 8;
+// 反复登记、注销不同定义时的上限，超过后只能登记用过的定义
+pub const MAX_DEFINITIONS: usize = 64;
+const MAX_ID_LEN: usize = 32;
+// n 最多是内核默认规模的这么多倍，避免一个登记把整套测试拖到几十分钟
+pub const MAX_N_FACTOR: u64 = 4;
+
+pub struct CustomCase {
+    pub def: &'static CaseDef,
+    pub kernel: &'static str,
+    // 提交登记的插件名，描述里没给时为 None
+    pub owner: Option<String>,
+}
+
+impl CustomCase {
+    pub fn json(&self) -> Value {
+        json!({
+            "case": self.def.id,
+            "kernel": self.kernel,
+            "n": self.def.n,
+            "owner": self.owner,
+            "description": self.def.description,
+        })
+    }
+}
+
+static CUSTOM: Mutex<Vec<CustomCase>> = Mutex::new(Vec::new());
+// 本次会话泄漏过的全部定义及其内核，注销后仍留在这里供复用
+static DEFINITIONS: Mutex<Vec<(&'static str, &'static CaseDef)>> = Mutex::new(Vec::new());
+
+fn registry() -> std::sync::MutexGuard<'static, Vec<CustomCase>> {
+    CUSTOM
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn valid_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_ID_LEN
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// descriptor: {"case": "...", "kernel": "T1_INT32_MIX", "n"?: 1000000, "owner"?: "...", "description"?: "..."}，
+// 省略 n 时用内核的默认规模。同名用例再次登记时替换原描述
+pub fn register(descriptor: &Value) -> Result<&'static CaseDef, String> {
+    let field = |key: &str| descriptor.get(key).and_then(Value::as_str);
+    let id = field("case").ok_or("缺少 case")?;
+    if !valid_id(id) {
+        return Err(format!(
+            "用例名只能由字母、数字和下划线组成，最长 {} 个字符",
+            MAX_ID_LEN
+        ));
+    }
+    if CASES.iter().any(|case| case.id == id) {
+        return Err(format!("{} 与内置用例重名", id));
+    }
+    let kernel_id = field("kernel").ok_or("缺少 kernel")?;
+    let kernel = CASES
+        .iter()
+        .find(|case| case.id == kernel_id)
+        .ok_or_else(|| format!("未知的内核: {}", kernel_id))?;
+    let limit = kernel.n.saturating_mul(MAX_N_FACTOR);
+    let n = match descriptor.get("n") {
+        None => kernel.n,
+        Some(value) => value
+            .as_u64()
+            .filter(|n| (1..=limit).contains(n))
+            .ok_or_else(|| format!("n 须在 1..={} 之间", limit))?,
+    };
+    let owner = field("owner").map(str::to_string);
+    let description = match field("description") {
+        Some(text) => text.to_string(),
+        None => format!(
+            "{} 登记的自定义用例：内核 {}，n = {}",
+            owner.as_deref().unwrap_or("外部插件"),
+            kernel.id,
+            n
+        ),
+    };
+
+    let mut custom = registry();
+    let existing = custom.iter().position(|case| case.def.id == id);
+    if existing.is_none() && custom.len() >= MAX_CUSTOM_CASES {
+        return Err(format!("最多登记 {} 个自定义用例", MAX_CUSTOM_CASES));
+    }
+    let def = definition(id, kernel, n, description)?;
+    let case = CustomCase {
+        def,
+        kernel: kernel.id,
+        owner,
+    };
+    tracing::info!("registered custom case {} ({} n={})", id, kernel.id, n);
+    match existing {
+        Some(index) => custom[index] = case,
+        None => custom.push(case),
+    }
+    Ok(def)
+}
+
+// 其余字段都取自内核，id、内核、n 与描述相同即为同一定义
+fn definition(
+    id: &str,
+    kernel: &'static CaseDef,
+    n: u64,
+    description: String,
+) -> Result<&'static CaseDef, String> {
+    let mut definitions = DEFINITIONS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(&(_, def)) = definitions.iter().find(|(kernel_id, def)| {
+        *kernel_id == kernel.id && def.id == id && def.n == n && def.description == description
+    }) {
+        return Ok(def);
+    }
+    if definitions.len() >= MAX_DEFINITIONS {
+        return Err(format!(
+            "本次会话已登记过 {} 种不同的用例定义，只能复用已有的定义",
+            MAX_DEFINITIONS
+        ));
+    }
+    let def: &'static CaseDef = Box::leak(Box::new(CaseDef {
+        id: Box::leak(id.to_string().into_boxed_str()),
+        description: Box::leak(description.into_boxed_str()),
+        n,
+        ops_per_n: kernel.ops_per_n,
+        work: kernel.work,
+        // 参考 digest 只对默认规模成立
+        reference: kernel.reference.filter(|_| n == kernel.n),
+        baseline: None,
+        buffer_bytes: kernel.buffer_bytes,
+        prepare: kernel.prepare,
+        reset: kernel.reset,
+        needs_render: kernel.needs_render,
+        run: kernel.run,
+        finalize: kernel.finalize,
+    }));
+    definitions.push((kernel.id, def));
+    Ok(def)
+}
+
+pub fn unregister(id: &str) -> bool {
+    let mut custom = registry();
+    let before = custom.len();
+    custom.retain(|case| case.def.id != id);
+    custom.len() != before
+}
+
+pub fn custom_cases() -> Vec<&'static CaseDef> {
+    registry().iter().map(|case| case.def).collect()
+}
+
+pub fn find_custom(id: &str) -> Option<&'static CaseDef> {
+    registry()
+        .iter()
+        .find(|case| case.def.id == id)
+        .map(|case| case.def)
+}
+
+// 结果 JSON 中该用例的 custom 段，内置用例返回 None
+pub fn custom_json(id: &str) -> Option<Value> {
+    registry()
+        .iter()
+        .find(|case| case.def.id == id)
+        .map(CustomCase::json)
+}
+
+pub fn registry_json() -> Value {
+    registry().iter().map(CustomCase::json).collect()
+}
@@ -0,0 +1,58 @@
+// 按设备类别选择默认的用例集合与预热/重复次数。类别由宿主报告的平台名推断，
+// 插件在手机或桌面版 AstroBox 中运行，手表端不会直接加载插件
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DeviceClass {
+    Phone,
+    Desktop,
+    Unknown,
+}
+
+pub struct ClassDefaults {
+    pub warmup: usize,
+    pub repeats: usize,
+    pub skip_cases: &'static [&'static str],
+    // 写入 JSON 与界面的调整说明
+    pub note: Option<&'static str>,
+}
+
+impl DeviceClass {
+    pub fn from_platform(platform: &str) -> Self {
+        let platform = platform.trim().to_ascii_lowercase();
+        if ["android", "ios"].iter().any(|p| platform.contains(p)) {
+            DeviceClass::Phone
+        } else if ["windows", "macos", "darwin", "linux"]
+            .iter()
+            .any(|p| platform.contains(p))
+        {
+            DeviceClass::Desktop
+        } else {
+            DeviceClass::Unknown
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DeviceClass::Phone => "phone",
+            DeviceClass::Desktop => "desktop",
+            DeviceClass::Unknown => "unknown",
+        }
+    }
+
+    pub fn defaults(self) -> ClassDefaults {
+        match self {
+            // 手机容易发热降频，少跑几轮；T17 会反复替换整个界面，放到手动开启
+            DeviceClass::Phone => ClassDefaults {
+                warmup: 2,
+                repeats: 5,
+                skip_cases: &["T17_UI_RENDER"],
+                note: Some("手机：预热 2 次、重复 5 次，跳过 T17_UI_RENDER"),
+            },
+            DeviceClass::Desktop | DeviceClass::Unknown => ClassDefaults {
+                warmup: super::BENCH_WARMUP,
+                repeats: super::BENCH_REPEATS,
+                skip_cases: &[],
+                note: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// 开跑前的内存护栏。psys_host 不提供空闲内存与 CPU 信息（见 conditions::UNAVAILABLE），
+// 这里按各用例登记的 buffer_bytes 用 try_reserve 试探分配：失败时先释放前面用例缓存的缓冲区再试，
+// 仍失败就跳过该用例并写明原因，而不是在分配失败时直接终止插件。
+// 缩小缓冲区会改变 digest，结果无法再与参考值和其他移植版对照，所以只跳过不缩放
+use serde_json::{Value, json};
+
+use super::cases::{CaseDef, Workspace};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    // 释放了之前用例缓存的缓冲区后分配成功
+    ReleasedWorkspace,
+    Skipped,
+}
+
+impl Action {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Action::ReleasedWorkspace => "released_workspace",
+            Action::Skipped => "skipped",
+        }
+    }
+}
+
+pub struct Decision {
+    pub case: &'static str,
+    pub bytes: usize,
+    pub action: Action,
+}
+
+impl Decision {
+    pub fn json(&self) -> Value {
+        json!({ "case": self.case, "bytes": self.bytes, "action": self.action.as_str() })
+    }
+
+    pub fn reason(&self) -> String {
+        format!(
+            "{} 内存不足: 需要约 {:.1} MiB 缓冲区，试探分配失败",
+            self.case,
+            self.bytes as f64 / (1024.0 * 1024.0)
+        )
+    }
+}
+
+// 试探用的内存立即释放，随后的 prepare 可以复用
+fn can_allocate(bytes: usize) -> bool {
+    let mut probe: Vec<u8> = Vec::new();
+    probe.try_reserve_exact(bytes).is_ok()
+}
+
+// 分配顺利时返回 None
+pub fn check(case: &CaseDef, workspace: &mut Workspace) -> Option<Decision> {
+    if case.buffer_bytes == 0 || can_allocate(case.buffer_bytes) {
+        return None;
+    }
+    *workspace = Workspace::default();
+    let action = if can_allocate(case.buffer_bytes) {
+        Action::ReleasedWorkspace
+    } else {
+        Action::Skipped
+    };
+    tracing::warn!(
+        "{} needs {} bytes: {}",
+        case.id,
+        case.buffer_bytes,
+        action.as_str()
+    );
+    Some(Decision {
+        case: case.id,
+        bytes: case.buffer_bytes,
+        action,
+    })
+}
@@ -0,0 +1,26 @@
+// -------- xorshift32 PRNG (pure 32-bit, cross-lang) --------
+pub struct XorShift32 {
+    x: u32,
+}
+
+impl XorShift32 {
+    pub fn new(seed: u32) -> Self {
+        let x = if seed == 0 { 0x6D2B79F5 } else { seed };
+        Self { x }
+    }
+
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.x;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.x = x;
+        x
+    }
+
+    #[inline]
+    pub fn next_f64_01(&mut self) -> f64 {
+        (self.next_u32() as f64) / 4294967296.0 // 2^32
+    }
+}
@@ -0,0 +1,230 @@
+// 按耗时加权的进度：已完成的步按实测耗时计，当前步按块进度折算，剩余步按各用例的预计单步耗时估计。
+// 各用例单步耗时相差很大，按步数平分时进度条会在慢用例上停住，剩余时间也估不准。
+// 预计单步耗时依次取：该用例本次已完成步的平均值、历史 p50（hint_ms）、
+// 按操作数和本次已测得的每操作耗时折算。不同用例的“操作”粒度不同，最后一种只是粗估
+use super::{
+    BenchPhase, BenchStepStatus, BenchmarkConfig, Profile, ProgressUpdate, SAMPLE_SECS,
+    SUSTAINED_KERNEL, ab, all_cases, find_case, sustained,
+};
+
+// 计划中的一个用例
+pub struct PlannedCase {
+    pub id: &'static str,
+    pub steps: usize,
+    // 单步的基本操作数，即 n * ops_per_n
+    pub ops_per_step: f64,
+    // 之前运行的单步耗时，由调用方从累计统计里填入
+    pub hint_ms: Option<f64>,
+}
+
+// 与 total_steps 的计数方式一致
+pub fn step_plan(config: &BenchmarkConfig) -> Vec<PlannedCase> {
+    let planned = |id: &'static str, steps: usize| PlannedCase {
+        id,
+        steps,
+        ops_per_step: find_case(id).map_or(1.0, |case| {
+            let n = match config.profile {
+                Profile::Standard => config.n_override(id).unwrap_or(case.n),
+                Profile::Sustained | Profile::Ab => case.n,
+            };
+            (n * case.ops_per_n) as f64
+        }),
+        hint_ms: None,
+    };
+    match config.profile {
+        Profile::Sustained => vec![PlannedCase {
+            hint_ms: Some(SAMPLE_SECS as f64 * 1000.0),
+            ..planned(
+                SUSTAINED_KERNEL,
+                sustained::sample_count(config.sustained_minutes),
+            )
+        }],
+        Profile::Ab => ab::ab_cases(config)
+            .map(|case| planned(case.id, 2 * (config.warmup + config.repeats)))
+            .collect(),
+        Profile::Standard => {
+            let cold = usize::from(config.cold_start);
+            all_cases()
+                .into_iter()
+                .filter(|case| !config.skip_cases.contains(&case.id))
+                .map(|case| planned(case.id, cold + config.warmup + config.repeats))
+                .collect()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct ProgressSplit {
+    // 已完成的热身（含冷启动）与正式测量分别占预计总耗时的比例
+    pub warmup: f64,
+    pub measure: f64,
+    // 预计剩余的内核耗时，不含用例之间的准备时间
+    pub remaining_ms: Option<f64>,
+}
+
+impl ProgressSplit {
+    pub fn percent(&self) -> f64 {
+        ((self.warmup + self.measure) * 100.0).min(100.0)
+    }
+}
+
+struct CaseProgress {
+    plan: PlannedCase,
+    done_steps: usize,
+    done_ms: f64,
+}
+
+struct CurrentStep {
+    case: usize,
+    warmup: bool,
+    elapsed_ms: f64,
+    // 已完成块的比例
+    fraction: f64,
+}
+
+#[derive(Default)]
+pub struct ProgressMeter {
+    cases: Vec<CaseProgress>,
+    warmup_ms: f64,
+    measure_ms: f64,
+    // 正在进行的一步
+    current: Option<CurrentStep>,
+    complete: bool,
+}
+
+impl ProgressMeter {
+    pub fn new(plan: Vec<PlannedCase>) -> Self {
+        ProgressMeter {
+            cases: plan
+                .into_iter()
+                .map(|plan| CaseProgress {
+                    plan,
+                    done_steps: 0,
+                    done_ms: 0.0,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn observe(&mut self, update: &ProgressUpdate) {
+        let Some(case) = self.cases.iter().position(|c| c.plan.id == update.bench_id) else {
+            return;
+        };
+        let warmup = !matches!(update.phase, BenchPhase::Measure);
+        match update.status {
+            BenchStepStatus::Started => {
+                self.current = Some(CurrentStep {
+                    case,
+                    warmup,
+                    elapsed_ms: 0.0,
+                    fraction: 0.0,
+                });
+            }
+            BenchStepStatus::Running => {
+                self.current = Some(CurrentStep {
+                    case,
+                    warmup,
+                    elapsed_ms: update.elapsed_ms,
+                    fraction: if update.chunk_total > 0 {
+                        update.chunk_index as f64 / update.chunk_total as f64
+                    } else {
+                        0.0
+                    },
+                });
+            }
+            // 被判为噪声重跑的迭代不会发出 Finished，耗时也不计入
+            BenchStepStatus::Finished => {
+                self.current = None;
+                let progress = &mut self.cases[case];
+                progress.done_steps += 1;
+                progress.done_ms += update.elapsed_ms;
+                if warmup {
+                    self.warmup_ms += update.elapsed_ms;
+                } else {
+                    self.measure_ms += update.elapsed_ms;
+                }
+            }
+        }
+    }
+
+    // 运行结束（含中止）时把剩余部分视为完成
+    pub fn complete(&mut self) {
+        for case in &mut self.cases {
+            case.plan.steps = case.done_steps;
+        }
+        self.current = None;
+        self.complete = true;
+    }
+
+    // 本次已测得的每操作耗时；还没有完成的步时用当前步的预计耗时
+    fn ms_per_op(&self) -> Option<f64> {
+        let (ms, ops) = self.cases.iter().fold((0.0, 0.0), |(ms, ops), case| {
+            let steps = case.done_steps as f64;
+            (ms + case.done_ms, ops + steps * case.plan.ops_per_step)
+        });
+        if ops > 0.0 && ms > 0.0 {
+            return Some(ms / ops);
+        }
+        let step = self.current.as_ref().filter(|step| step.fraction > 0.0)?;
+        let ops = self.cases[step.case].plan.ops_per_step;
+        Some(step.elapsed_ms / step.fraction / ops)
+    }
+
+    fn step_estimate(&self, case: &CaseProgress, ms_per_op: Option<f64>) -> Option<f64> {
+        if case.done_steps > 0 {
+            Some(case.done_ms / case.done_steps as f64)
+        } else {
+            case.plan
+                .hint_ms
+                .or_else(|| ms_per_op.map(|ms| ms * case.plan.ops_per_step))
+        }
+    }
+
+    pub fn split(&self) -> ProgressSplit {
+        let done_ms = self.warmup_ms + self.measure_ms;
+        let ms_per_op = self.ms_per_op();
+        let mut current_ms = 0.0;
+        let mut remaining = Some(0.0);
+        for (index, case) in self.cases.iter().enumerate() {
+            let per_step = self.step_estimate(case, ms_per_op);
+            let mut left = case.plan.steps.saturating_sub(case.done_steps);
+            if let Some(step) = self.current.as_ref().filter(|step| step.case == index) {
+                current_ms = step.elapsed_ms;
+                left = left.saturating_sub(1);
+                let projected = if step.fraction > 0.0 {
+                    Some(step.elapsed_ms / step.fraction)
+                } else {
+                    per_step
+                };
+                let current_left = projected.map(|ms| (ms - step.elapsed_ms).max(0.0));
+                remaining = remaining.zip(current_left).map(|(a, b)| a + b);
+            }
+            if left > 0 {
+                remaining = remaining.zip(per_step).map(|(a, b)| a + b * left as f64);
+            }
+        }
+        let Some(remaining_ms) = remaining else {
+            return ProgressSplit::default();
+        };
+        let estimate = done_ms + current_ms + remaining_ms;
+        if estimate <= 0.0 {
+            // 所有用例都被跳过时整条算作完成
+            return ProgressSplit {
+                measure: if self.complete { 1.0 } else { 0.0 },
+                ..Default::default()
+            };
+        }
+        let current_warmup = self.current.as_ref().is_some_and(|step| step.warmup);
+        let (warmup_current, measure_current) = if current_warmup {
+            (current_ms, 0.0)
+        } else {
+            (0.0, current_ms)
+        };
+        ProgressSplit {
+            warmup: (self.warmup_ms + warmup_current) / estimate,
+            measure: (self.measure_ms + measure_current) / estimate,
+            remaining_ms: Some(remaining_ms),
+        }
+    }
+}
@@ -0,0 +1,355 @@
+// 耗时统计：分位数采用 type-7 线性插值（与 R / NumPy 默认一致），
+// 均值与方差用 Welford 在线算法，多轮结果可按样本数合并
+use serde_json::{Map, Value, json};
+
+use super::ms3;
+
+#[derive(Default)]
+pub struct BenchStats {
+    pub min: f64,
+    pub p50: f64,
+    pub p95: f64,
+    pub max: f64,
+    pub mean: f64,
+    // 样本标准差，少于 2 个样本时为 0
+    pub stddev: f64,
+    // summarize 额外要求的 (百分位, 值)，按请求的顺序；calc_stats 不填
+    pub percentiles: Vec<(f64, f64)>,
+}
+
+impl BenchStats {
+    // 与结果 JSON 的 time 块字段相同，数值保留 3 位小数，单位由调用方决定；
+    // 额外的百分位放在 percentiles 里，键为 "p99"、"p99.9" 这样的形式
+    pub fn json(&self) -> Value {
+        let mut value = json!({
+            "min": ms3(self.min),
+            "p50": ms3(self.p50),
+            "p95": ms3(self.p95),
+            "max": ms3(self.max),
+            "mean": ms3(self.mean),
+            "stddev": ms3(self.stddev),
+        });
+        if !self.percentiles.is_empty() {
+            let percentiles: Map<String, Value> = self
+                .percentiles
+                .iter()
+                .map(|&(p, v)| (format!("p{}", p), json!(ms3(v))))
+                .collect();
+            value["percentiles"] = percentiles.into();
+        }
+        value
+    }
+}
+
+// sorted 必须已升序排列；空切片返回 NaN，q 超出 [0, 1] 时截断
+pub fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let n = sorted.len();
+    if n == 0 {
+        return f64::NAN;
+    }
+    let h = (n - 1) as f64 * q.clamp(0.0, 1.0);
+    let lo = h.floor() as usize;
+    let hi = (lo + 1).min(n - 1);
+    sorted[lo] + (h - lo as f64) * (sorted[hi] - sorted[lo])
+}
+
+pub fn median(sorted: &[f64]) -> f64 {
+    quantile(sorted, 0.5)
+}
+
+pub fn p95(sorted: &[f64]) -> f64 {
+    quantile(sorted, 0.95)
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct Welford {
+    count: u64,
+    mean: f64,
+    // 与均值之差的平方和
+    m2: f64,
+}
+
+impl Welford {
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    // 合并另一轮的累计量，等价于把两轮样本放在一起重新计算（Chan 等人的并行公式）
+    pub fn merge(&mut self, other: &Welford) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * (self.count * other.count) as f64 / count as f64;
+        self.count = count;
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 { f64::NAN } else { self.mean }
+    }
+
+    // 样本方差（除以 n - 1）
+    pub fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+
+    pub fn stddev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl FromIterator<f64> for Welford {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut acc = Welford::default();
+        for value in iter {
+            acc.push(value);
+        }
+        acc
+    }
+}
+
+pub fn calc_stats(times: &mut [f64]) -> BenchStats {
+    times.sort_by(f64::total_cmp);
+    let acc: Welford = times.iter().copied().collect();
+    BenchStats {
+        min: times.first().copied().unwrap_or(0.0),
+        p50: median(times),
+        p95: p95(times),
+        max: times.last().copied().unwrap_or(0.0),
+        mean: if times.is_empty() { 0.0 } else { acc.mean() },
+        stddev: acc.stddev(),
+        percentiles: Vec::new(),
+    }
+}
+
+// 供其他测量复用的纯函数：对任意一组样本（不必排序，单位自定）给出与用例结果相同的统计，
+// 另按 percentiles 计算额外的百分位（0–100，超出时截断）。非有限值（NaN、无穷）不计入样本。
+// 不修改输入，也不依赖插件状态
+pub fn summarize(samples: &[f64], percentiles: &[f64]) -> BenchStats {
+    let mut sorted: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+    let mut stats = calc_stats(&mut sorted);
+    stats.percentiles = percentiles
+        .iter()
+        .map(|&p| (p, quantile(&sorted, p / 100.0)))
+        .collect();
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * a.abs().max(b.abs()).max(1.0)
+    }
+
+    // 直接按定义的两遍算法作对照
+    fn naive_variance(values: &[f64]) -> f64 {
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0)
+    }
+
+    #[test]
+    fn quantile_empty_is_nan() {
+        assert!(quantile(&[], 0.5).is_nan());
+        assert!(median(&[]).is_nan());
+        assert!(p95(&[]).is_nan());
+    }
+
+    #[test]
+    fn quantile_single_sample() {
+        for q in [0.0, 0.25, 0.5, 0.95, 1.0] {
+            assert_eq!(quantile(&[7.0], q), 7.0);
+        }
+    }
+
+    #[test]
+    fn quantile_endpoints_are_min_and_max() {
+        let sorted = [1.0, 2.0, 4.0, 8.0, 16.0];
+        assert_eq!(quantile(&sorted, 0.0), 1.0);
+        assert_eq!(quantile(&sorted, 1.0), 16.0);
+        assert_eq!(quantile(&sorted, -0.5), 1.0);
+        assert_eq!(quantile(&sorted, 1.5), 16.0);
+    }
+
+    #[test]
+    fn median_odd_and_even() {
+        assert_eq!(median(&[1.0, 2.0, 3.0]), 2.0);
+        assert_eq!(median(&[1.0, 2.0, 3.0, 10.0]), 2.5);
+        assert_eq!(median(&[5.0, 5.0]), 5.0);
+    }
+
+    #[test]
+    fn quantile_matches_type7_reference() {
+        // 对照值来自 numpy.quantile（默认 method="linear"，即 type-7）
+        let sorted = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        assert!(close(quantile(&sorted, 0.25), 3.25));
+        assert!(close(quantile(&sorted, 0.5), 5.5));
+        assert!(close(quantile(&sorted, 0.9), 9.1));
+        assert!(close(p95(&sorted), 9.55));
+    }
+
+    #[test]
+    fn p95_interpolates_for_small_counts() {
+        // 旧实现按四舍五入取下标：9 个样本时 (8 * 0.95).round() = 8，p95 直接等于 max
+        let sorted = [10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 30.0];
+        assert!(close(p95(&sorted), 17.0 + 0.6 * 13.0));
+        assert!(p95(&sorted) < 30.0);
+        // 2 个样本时落在两者之间而不是跳到较大值
+        assert!(close(p95(&[1.0, 2.0]), 1.95));
+    }
+
+    #[test]
+    fn quantile_is_monotonic_in_q() {
+        let sorted = [0.5, 0.9, 1.0, 3.0, 3.0, 7.5, 12.0];
+        let mut last = f64::NEG_INFINITY;
+        for step in 0..=100 {
+            let value = quantile(&sorted, step as f64 / 100.0);
+            assert!(value >= last);
+            last = value;
+        }
+    }
+
+    #[test]
+    fn quantile_with_ties() {
+        let sorted = [2.0, 2.0, 2.0, 2.0];
+        for q in [0.1, 0.5, 0.95] {
+            assert_eq!(quantile(&sorted, q), 2.0);
+        }
+    }
+
+    #[test]
+    fn welford_matches_two_pass() {
+        let values = [12.5, 13.1, 12.9, 15.0, 12.7, 13.3, 40.2, 12.8];
+        let acc: Welford = values.iter().copied().collect();
+        assert_eq!(acc.count(), values.len() as u64);
+        assert!(close(acc.mean(), values.iter().sum::<f64>() / 8.0));
+        assert!(close(acc.variance(), naive_variance(&values)));
+        assert!(close(acc.stddev(), naive_variance(&values).sqrt()));
+    }
+
+    #[test]
+    fn welford_is_stable_with_large_offset() {
+        // 大偏移下 E[x²] - E[x]² 会因抵消丢失精度，Welford 不会
+        let values: Vec<f64> = (0..100).map(|i| 1e9 + (i % 4) as f64).collect();
+        let acc: Welford = values.iter().copied().collect();
+        // 0..=3 各 25 个：Σ(x - 1.5)² = 125
+        assert!((acc.variance() - 125.0 / 99.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn welford_small_counts() {
+        let empty = Welford::default();
+        assert!(empty.mean().is_nan());
+        assert_eq!(empty.variance(), 0.0);
+
+        let one: Welford = [3.0].into_iter().collect();
+        assert_eq!(one.mean(), 3.0);
+        assert_eq!(one.variance(), 0.0);
+    }
+
+    #[test]
+    fn welford_merge_equals_single_pass() {
+        let a = [1.0, 2.0, 3.0, 4.0];
+        let b = [10.0, 20.0, 30.0];
+        let mut merged: Welford = a.iter().copied().collect();
+        merged.merge(&b.iter().copied().collect());
+        let all: Welford = a.iter().chain(b.iter()).copied().collect();
+        assert_eq!(merged.count(), all.count());
+        assert!(close(merged.mean(), all.mean()));
+        assert!(close(merged.variance(), all.variance()));
+    }
+
+    #[test]
+    fn welford_merge_with_empty() {
+        let values: Welford = [4.0, 6.0].into_iter().collect();
+        let mut left = Welford::default();
+        left.merge(&values);
+        assert!(close(left.mean(), 5.0));
+        assert!(close(left.variance(), 2.0));
+
+        let mut right = values;
+        right.merge(&Welford::default());
+        assert_eq!(right.count(), 2);
+        assert!(close(right.variance(), 2.0));
+    }
+
+    #[test]
+    fn calc_stats_sorts_and_summarizes() {
+        let mut times = [5.0, 1.0, 4.0, 2.0, 3.0];
+        let stats = calc_stats(&mut times);
+        assert_eq!(times, [1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 5.0);
+        assert_eq!(stats.p50, 3.0);
+        assert!(close(stats.p95, 4.8));
+        assert!(close(stats.mean, 3.0));
+        assert!(close(stats.stddev, 2.5f64.sqrt()));
+    }
+
+    #[test]
+    fn calc_stats_empty() {
+        let stats = calc_stats(&mut []);
+        assert_eq!(stats.min, 0.0);
+        assert_eq!(stats.max, 0.0);
+        assert_eq!(stats.mean, 0.0);
+        assert_eq!(stats.stddev, 0.0);
+        assert!(stats.p50.is_nan());
+    }
+
+    #[test]
+    fn summarize_matches_calc_stats() {
+        let samples = [5.0, 1.0, 4.0, 2.0, 3.0];
+        let stats = summarize(&samples, &[]);
+        let mut sorted = samples;
+        let expected = calc_stats(&mut sorted);
+        assert_eq!(samples, [5.0, 1.0, 4.0, 2.0, 3.0]);
+        assert_eq!(stats.p50, expected.p50);
+        assert!(close(stats.p95, expected.p95));
+        assert!(close(stats.stddev, expected.stddev));
+        assert!(stats.percentiles.is_empty());
+    }
+
+    #[test]
+    fn summarize_extra_percentiles() {
+        let samples: Vec<f64> = (1..=101).map(f64::from).collect();
+        let stats = summarize(&samples, &[99.0, 0.0, 150.0]);
+        assert_eq!(
+            stats.percentiles,
+            [(99.0, 100.0), (0.0, 1.0), (150.0, 101.0)]
+        );
+    }
+
+    #[test]
+    fn summarize_skips_non_finite() {
+        let stats = summarize(&[f64::NAN, 2.0, f64::INFINITY, 4.0], &[50.0]);
+        assert_eq!(stats.min, 2.0);
+        assert_eq!(stats.max, 4.0);
+        assert_eq!(stats.percentiles, [(50.0, 3.0)]);
+    }
+
+    #[test]
+    fn summarize_json_keys() {
+        let json = summarize(&[1.0, 2.0], &[99.9]).json();
+        assert_eq!(json["min"], 1.0);
+        assert_eq!(json["percentiles"]["p99.9"], 1.999);
+    }
+}
@@ -0,0 +1,177 @@
+// 持续负载：连续运行 T1 整数内核若干分钟，每 SAMPLE_SECS 秒记一次吞吐。
+// 轻薄设备往往在几分钟后降频，持续/峰值比直接反映降频幅度
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+
+use crate::{build_info, timestamp};
+
+use super::cases::{CaseRun, Workspace, compute};
+use super::{
+    BENCH_SEED, BenchPhase, BenchStepStatus, BenchmarkConfig, CANCEL_REQUESTED, Host,
+    ProgressUpdate, clear_requests, ms3, wait_while_paused,
+};
+
+pub const SUSTAINED_KERNEL: &str = "T1_INT32_MIX";
+pub const SAMPLE_SECS: u64 = 10;
+// 设置里可选的持续时长（分钟）
+pub const MINUTES_CHOICES: [u32; 4] = [1, 5, 10, 30];
+// 每次调用内核的迭代数，单次约数十毫秒，采样窗口的边界误差可以忽略
+const SLICE_N: u64 = 4_000_000;
+
+pub struct SustainedSample {
+    // 窗口结束时距开始的秒数
+    pub t_s: f64,
+    pub ops_per_sec: f64,
+}
+
+pub struct SustainedResult {
+    pub minutes: u32,
+    pub samples: Vec<SustainedSample>,
+    pub peak_ops_per_sec: f64,
+    // 后一半采样的中位数，避开开头尚未降频的阶段
+    pub sustained_ops_per_sec: f64,
+    pub ratio: f64,
+    pub cancelled: bool,
+    pub started_unix_ms: u64,
+    pub json: String,
+}
+
+pub fn sample_count(minutes: u32) -> usize {
+    (minutes as u64 * 60 / SAMPLE_SECS) as usize
+}
+
+pub fn run_sustained<P>(
+    config: &BenchmarkConfig,
+    host: &dyn Host,
+    mut progress: P,
+) -> SustainedResult
+where
+    P: FnMut(ProgressUpdate),
+{
+    clear_requests();
+    let started_unix_ms = timestamp::now_unix_ms();
+    let total = sample_count(config.sustained_minutes);
+    let window = Duration::from_secs(SAMPLE_SECS);
+    let mut workspace = Workspace::default();
+    let mut samples: Vec<SustainedSample> = Vec::with_capacity(total);
+    let mut cancelled = false;
+    let mut slice = 0u32;
+    let mut started = Instant::now();
+
+    for index in 1..=total {
+        // 暂停的时长不计入曲线的时间轴
+        started += wait_while_paused();
+        let base = ProgressUpdate {
+            bench_id: SUSTAINED_KERNEL,
+            phase: BenchPhase::Measure,
+            index,
+            total,
+            completed_steps: index - 1,
+            total_steps: total,
+            status: BenchStepStatus::Started,
+            chunk_index: 0,
+            chunk_total: 0,
+            elapsed_ms: 0.0,
+            ops_per_sec: samples.last().map(|s| s.ops_per_sec),
+        };
+        progress(base);
+
+        let window_start = Instant::now();
+        let mut ops = 0u64;
+        while window_start.elapsed() < window {
+            if CANCEL_REQUESTED.load(Ordering::Relaxed) {
+                cancelled = true;
+                break;
+            }
+            let mut ctx = CaseRun {
+                seed: BENCH_SEED.wrapping_add(slice),
+                n: SLICE_N,
+                barrier: config.barrier,
+                chunks: 1,
+                chunk_stride: 1,
+                workspace: &mut workspace,
+                render_target: None,
+                host,
+                error: None,
+            };
+            compute::int32_mix(&mut ctx, &mut |_, _| {});
+            slice = slice.wrapping_add(1);
+            ops += SLICE_N;
+        }
+        if cancelled {
+            break;
+        }
+
+        let secs = window_start.elapsed().as_secs_f64();
+        let ops_per_sec = ops as f64 / secs;
+        samples.push(SustainedSample {
+            t_s: started.elapsed().as_secs_f64(),
+            ops_per_sec,
+        });
+        progress(ProgressUpdate {
+            completed_steps: index,
+            status: BenchStepStatus::Finished,
+            elapsed_ms: secs * 1000.0,
+            ops_per_sec: Some(ops_per_sec),
+            ..base
+        });
+    }
+
+    let peak = samples.iter().map(|s| s.ops_per_sec).fold(0.0, f64::max);
+    let mut tail: Vec<f64> = samples[samples.len() / 2..]
+        .iter()
+        .map(|s| s.ops_per_sec)
+        .collect();
+    tail.sort_by(f64::total_cmp);
+    let sustained = if tail.is_empty() {
+        0.0
+    } else {
+        super::median(&tail)
+    };
+    let ratio = if peak > 0.0 { sustained / peak } else { 0.0 };
+    tracing::info!(
+        "sustained done. samples={} peak={:.0} sustained={:.0} ratio={:.3}",
+        samples.len(),
+        peak,
+        sustained,
+        ratio
+    );
+
+    let json = json!({
+        "lang": "rust",
+        "started_at": timestamp::iso8601_utc(started_unix_ms),
+        "env": host.env_json(),
+        "build": build_info::json(),
+        "seed": BENCH_SEED,
+        "profile": "sustained",
+        "params": {
+            "kernel": SUSTAINED_KERNEL,
+            "minutes": config.sustained_minutes,
+            "sample_secs": SAMPLE_SECS,
+            "slice_n": SLICE_N,
+            "barrier": config.barrier.as_str(),
+            "heartbeat_log": config.heartbeat_log,
+        },
+        "cancelled": cancelled,
+        "peak_ops_per_sec": peak.round(),
+        "sustained_ops_per_sec": sustained.round(),
+        "sustained_ratio": ms3(ratio),
+        "series": samples
+            .iter()
+            .map(|s| json!({ "t_s": ms3(s.t_s), "ops_per_sec": s.ops_per_sec.round() }))
+            .collect::<Vec<_>>(),
+    });
+
+    SustainedResult {
+        minutes: config.sustained_minutes,
+        samples,
+        peak_ops_per_sec: peak,
+        sustained_ops_per_sec: sustained,
+        ratio,
+        cancelled,
+        started_unix_ms,
+        json: serde_json::to_string_pretty(&json).unwrap_or_default(),
+    }
+}
@@ -0,0 +1,89 @@
+// 用户设定的验收阈值：跑完后逐个用例给出 PASS/FAIL，用于设备 QA 验收
+use serde_json::{Value, json};
+
+use super::{BenchCaseResult, CaseOutcome};
+
+// 两项都为 None 时等同于未设定
+#[derive(Clone, Copy, Default)]
+pub struct CaseThreshold {
+    pub id: &'static str,
+    // p50 耗时上限
+    pub max_p50_ms: Option<f64>,
+    // 吞吐下限，单位与该用例的 throughput.unit 相同；没有吞吐指标的用例不能设定
+    pub min_score: Option<f64>,
+}
+
+impl CaseThreshold {
+    pub fn is_set(&self) -> bool {
+        self.max_p50_ms.is_some() || self.min_score.is_some()
+    }
+}
+
+pub struct Verdict {
+    pub passed: bool,
+    // 不通过的原因，通过时为空
+    pub reasons: Vec<String>,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        if self.passed { "pass" } else { "fail" }
+    }
+}
+
+pub fn evaluate(threshold: &CaseThreshold, case: &BenchCaseResult) -> Verdict {
+    let mut reasons = Vec::new();
+    if case.outcome != CaseOutcome::Ok {
+        reasons.push(format!("用例未完成 ({})", case.outcome.as_str()));
+    } else {
+        if let Some(limit) = threshold.max_p50_ms
+            && case.stats.p50 > limit
+        {
+            reasons.push(format!("p50 {:.3} ms > {} ms", case.stats.p50, limit));
+        }
+        if let Some(min) = threshold.min_score {
+            match &case.throughput {
+                Some(t) if t.value < min => {
+                    reasons.push(format!("{:.1} {} < {}", t.value, t.unit, min));
+                }
+                Some(_) => {}
+                None => reasons.push("没有吞吐指标，无法比较最低分".to_string()),
+            }
+        }
+    }
+    Verdict {
+        passed: reasons.is_empty(),
+        reasons,
+    }
+}
+
+// JSON 顶层 thresholds 段；没有设定任何阈值时为 null
+pub fn thresholds_json(thresholds: &[CaseThreshold], cases: &[BenchCaseResult]) -> Value {
+    let entries: Vec<Value> = cases
+        .iter()
+        .filter_map(|case| {
+            let threshold = thresholds.iter().find(|t| t.id == case.id && t.is_set())?;
+            let verdict = case.verdict.as_ref()?;
+            Some(json!({
+                "id": case.id,
+                "max_p50_ms": threshold.max_p50_ms,
+                "min_score": threshold.min_score,
+                "verdict": verdict.as_str(),
+                "reasons": verdict.reasons,
+            }))
+        })
+        .collect();
+    if entries.is_empty() {
+        return Value::Null;
+    }
+    let failed: Vec<&str> = cases
+        .iter()
+        .filter(|case| case.verdict.as_ref().is_some_and(|v| !v.passed))
+        .map(|case| case.id)
+        .collect();
+    json!({
+        "passed": failed.is_empty(),
+        "failed": failed,
+        "cases": entries,
+    })
+}
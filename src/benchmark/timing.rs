@@ -0,0 +1,144 @@
+// 计时环境探测：计时器分辨率与测试前的空载噪声，只依赖标准库
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use serde_json::{Value, json};
+
+use super::{median, ms3, p95};
+
+pub struct TimerProbe {
+    // 连续读取 Instant 观察到的最小非零间隔
+    pub resolution_ms: f64,
+    // sleep(1 ms) 的实际耗时中位数
+    pub sleep_1ms_ms: f64,
+    // yield_now 的耗时中位数
+    pub yield_ms: f64,
+    // 单次 Instant::now() 的开销，由连续调用的批次耗时折算
+    pub now_overhead_ms: f64,
+}
+
+impl TimerProbe {
+    // 结果以毫秒保留 3 位小数，分辨率比 0.1 ms 还粗时 p50 等统计已不可信
+    pub const COARSE_MS: f64 = 0.1;
+    // 块耗时不到开销的这么多倍时才扣除，更长的块里开销占比可以忽略
+    pub const CORRECT_BELOW: f64 = 1000.0;
+
+    pub fn is_coarse(&self) -> bool {
+        self.resolution_ms > Self::COARSE_MS
+    }
+
+    // 每个块的计时包含一次 Instant::now() 调用
+    pub fn chunk_overhead(&self) -> Duration {
+        Duration::from_secs_f64(self.now_overhead_ms / 1000.0)
+    }
+}
+
+const NOW_BATCH: u32 = 1000;
+
+// 测试前的空载噪声：反复计时一段极短的固定循环，耗时分布越散说明后台干扰越多
+pub struct NoiseProbe {
+    pub samples: usize,
+    pub p50_us: f64,
+    pub p95_us: f64,
+    // (p95 - p50) / p50，超过 BUSY_SCORE 视为设备繁忙
+    pub score: f64,
+}
+
+impl NoiseProbe {
+    pub const BUSY_SCORE: f64 = 0.25;
+
+    pub fn is_busy(&self) -> bool {
+        self.score > Self::BUSY_SCORE
+    }
+
+    pub fn json(&self) -> Value {
+        json!({
+            "noise_score": ms3(self.score),
+            "noise_p50_us": ms3(self.p50_us),
+            "noise_p95_us": ms3(self.p95_us),
+            "noise_samples": self.samples,
+            "busy": self.is_busy(),
+        })
+    }
+}
+
+const NOISE_PROBE_TIME: Duration = Duration::from_secs(2);
+// 单次约数十微秒，远大于计时分辨率
+const NOISE_LOOP_ITERS: u32 = 20_000;
+
+static TIMER_PROBE: OnceLock<TimerProbe> = OnceLock::new();
+
+fn median_of<F: FnMut() -> f64>(samples: usize, mut sample: F) -> f64 {
+    let mut values: Vec<f64> = (0..samples).map(|_| sample()).collect();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    median(&values)
+}
+
+// 总耗时约 10 ms，首次调用时测一次
+pub fn timer_probe() -> &'static TimerProbe {
+    TIMER_PROBE.get_or_init(|| {
+        let mut resolution = Duration::MAX;
+        for _ in 0..32 {
+            let start = Instant::now();
+            let mut now = Instant::now();
+            while now == start {
+                now = Instant::now();
+            }
+            resolution = resolution.min(now - start);
+        }
+        let elapsed_ms = |f: &dyn Fn()| {
+            let start = Instant::now();
+            f();
+            start.elapsed().as_secs_f64() * 1000.0
+        };
+        TimerProbe {
+            resolution_ms: resolution.as_secs_f64() * 1000.0,
+            sleep_1ms_ms: median_of(5, || {
+                elapsed_ms(&|| std::thread::sleep(Duration::from_millis(1)))
+            }),
+            yield_ms: median_of(15, || elapsed_ms(&std::thread::yield_now)),
+            now_overhead_ms: median_of(15, || {
+                elapsed_ms(&|| {
+                    for _ in 0..NOW_BATCH {
+                        std::hint::black_box(Instant::now());
+                    }
+                }) / NOW_BATCH as f64
+            }),
+        }
+    })
+}
+
+// 每次测试开始前调用，耗时 NOISE_PROBE_TIME
+pub fn noise_probe() -> NoiseProbe {
+    let mut times: Vec<f64> = Vec::new();
+    let started = Instant::now();
+    while started.elapsed() < NOISE_PROBE_TIME {
+        let start = Instant::now();
+        let mut x: u32 = 0x9E37_79B9;
+        for _ in 0..std::hint::black_box(NOISE_LOOP_ITERS) {
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+        }
+        std::hint::black_box(x);
+        times.push(start.elapsed().as_secs_f64() * 1e6);
+    }
+    times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let p50_us = median(&times);
+    let p95_us = p95(&times);
+    NoiseProbe {
+        samples: times.len(),
+        p50_us,
+        p95_us,
+        score: if p50_us > 0.0 {
+            (p95_us - p50_us) / p50_us
+        } else {
+            0.0
+        },
+    }
+}
+
+// 已经测过时返回结果，不会触发探测
+pub fn measured_timer_probe() -> Option<&'static TimerProbe> {
+    TIMER_PROBE.get()
+}
@@ -0,0 +1,55 @@
+// 逐块耗时的原始记录，导出为 Chrome trace（chrome://tracing、Perfetto 可直接打开），
+// 用来在时间线上看降频与抖动出现的位置
+use serde_json::json;
+
+use super::BenchPhase;
+
+pub struct ChunkSpan {
+    pub case: &'static str,
+    pub phase: BenchPhase,
+    // 该阶段内第几次迭代（从 1 开始）
+    pub iteration: usize,
+    pub chunk: usize,
+    // 相对本次运行开始的时间
+    pub start_us: f64,
+    // 已按计时校正扣除开销
+    pub dur_us: f64,
+}
+
+// 每个用例一条轨道，tid 即用例顺序
+pub fn chrome_trace(spans: &[ChunkSpan]) -> String {
+    let mut cases: Vec<&'static str> = Vec::new();
+    let mut events = Vec::with_capacity(spans.len() + 8);
+    for span in spans {
+        let tid = match cases.iter().position(|&case| case == span.case) {
+            Some(i) => i,
+            None => {
+                cases.push(span.case);
+                events.push(json!({
+                    "name": "thread_name",
+                    "ph": "M",
+                    "pid": 1,
+                    "tid": cases.len() - 1,
+                    "args": { "name": span.case },
+                }));
+                cases.len() - 1
+            }
+        };
+        events.push(json!({
+            "name": format!("{} #{}", span.phase.as_str(), span.iteration),
+            "cat": span.phase.as_str(),
+            "ph": "X",
+            "pid": 1,
+            "tid": tid,
+            "ts": (span.start_us * 1000.0).round() / 1000.0,
+            "dur": (span.dur_us * 1000.0).round() / 1000.0,
+            "args": { "case": span.case, "iteration": span.iteration, "chunk": span.chunk },
+        }));
+    }
+    let trace = json!({
+        "displayTimeUnit": "ms",
+        "otherData": { "lang": "rust", "spans": spans.len() },
+        "traceEvents": events,
+    });
+    serde_json::to_string(&trace).unwrap_or_default()
+}
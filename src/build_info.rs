@@ -0,0 +1,28 @@
+// 构建配置，由 build.rs 在编译时生成：profile、opt-level、LTO、目标与启用的目标特性
+use serde_json::{Value, json};
+
+include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
+
+// 调试断言开着或完全没有优化时，测出来的数字与发布构建差几倍到几十倍，没有参考意义
+pub fn is_debug() -> bool {
+    cfg!(debug_assertions) || OPT_LEVEL == "0"
+}
+
+pub fn target_features() -> Vec<&'static str> {
+    TARGET_FEATURES
+        .split(',')
+        .filter(|feature| !feature.is_empty())
+        .collect()
+}
+
+pub fn json() -> Value {
+    json!({
+        "profile": PROFILE,
+        "opt_level": OPT_LEVEL,
+        "lto": LTO,
+        "debug_assertions": cfg!(debug_assertions),
+        "target": TARGET,
+        "target_features": target_features(),
+        "debug_build": is_debug(),
+    })
+}
@@ -0,0 +1,100 @@
+// 宿主能力清单：逐项探测插件实际能用到的 psys_host 接口与 WASI 能力，显示在「关于」页，
+// 反馈特定宿主上的问题时一并附上。除事件外每次会话只探测一次
+use std::sync::OnceLock;
+
+use crate::astrobox::psys_host::device;
+use crate::event_probe::{self, ProbeStatus};
+use crate::{env, status_card, storage};
+
+// 探测存储时写入再删除的文件
+const PROBE_FILE: &str = "capability_probe.tmp";
+const PROBE_BYTES: &[u8] = b"probe";
+
+pub enum Capability {
+    Available(String),
+    // 宿主没有提供或本构建用不到
+    Missing(String),
+    // 接口存在但调用失败
+    Failed(String),
+}
+
+pub struct Inventory {
+    pub ui: Capability,
+    pub storage: Capability,
+    pub network: Capability,
+    pub device: Capability,
+    pub os: Capability,
+}
+
+static INVENTORY: OnceLock<Inventory> = OnceLock::new();
+
+fn probe_storage() -> Capability {
+    let result = storage::write(PROBE_FILE, PROBE_BYTES)
+        .and_then(|()| storage::read(PROBE_FILE))
+        .and_then(|bytes| storage::remove(PROBE_FILE).map(|()| bytes));
+    match result {
+        Ok(bytes) if bytes == PROBE_BYTES => {
+            Capability::Available(format!("WASI 预开放目录 {}/ 可读写", storage::DATA_DIR))
+        }
+        Ok(_) => Capability::Failed("读回的内容与写入的不一致".to_string()),
+        Err(err) => Capability::Failed(format!("{}/ 读写失败: {}", storage::DATA_DIR, err)),
+    }
+}
+
+// 只能发、不能确认宿主是否回投；回投情况取自事件往返探测，随探测进度变化所以不缓存
+pub fn events() -> Capability {
+    match event_probe::status() {
+        ProbeStatus::Done(_) => {
+            Capability::Available("send_event 可用，宿主会回投插件消息".to_string())
+        }
+        ProbeStatus::Waiting(_) | ProbeStatus::Idle => {
+            Capability::Available("send_event 可用，回投情况见测试页的事件往返".to_string())
+        }
+    }
+}
+
+// 登记在 on_load 里完成，这里只读结果
+pub fn status_card() -> Capability {
+    match status_card::registration() {
+        Some(Ok(())) => Capability::Available(format!(
+            "文字卡片 {} 已登记，测试期间显示进度",
+            status_card::CARD_ID
+        )),
+        Some(Err(err)) => Capability::Failed(err.clone()),
+        None => Capability::Missing("尚未登记".to_string()),
+    }
+}
+
+fn probe_device() -> Capability {
+    wit_bindgen::block_on(async {
+        let paired = device::get_device_list().await;
+        let connected = device::get_connected_device_list().await;
+        Capability::Available(format!(
+            "已配对 {} 台 · 已连接 {} 台",
+            paired.len(),
+            connected.len()
+        ))
+    })
+}
+
+fn probe_os() -> Capability {
+    match env::host_info() {
+        Some(host) => Capability::Available(format!(
+            "{} {} · {} · 语言区域 {}",
+            host.platform, host.os_version, host.arch, host.locale
+        )),
+        None => Capability::Failed("未取得宿主系统信息".to_string()),
+    }
+}
+
+// 需在插件环境中调用，会访问设备列表并在数据目录写入临时文件
+pub fn inventory() -> &'static Inventory {
+    INVENTORY.get_or_init(|| Inventory {
+        // 能打开「关于」页说明 ui::render 已经生效
+        ui: Capability::Available("ui::render 可用".to_string()),
+        storage: probe_storage(),
+        network: Capability::Missing("psys_host 没有网络接口，插件也不发起 HTTP 请求".to_string()),
+        device: probe_device(),
+        os: probe_os(),
+    })
+}
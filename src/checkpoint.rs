@@ -0,0 +1,175 @@
+// 崩溃取证：运行中每完成一次正式迭代就把进度与已有样本写入数据目录，正常结束（含取消）时删除。
+// 宿主中途杀掉插件时文件会留下，下次启动据此提示中断位置与已收集的部分统计
+use std::sync::Mutex;
+
+use serde_json::{Value, json};
+
+use crate::benchmark::{
+    self, BenchCaseResult, BenchPhase, BenchStepStatus, CaseOutcome, ProgressUpdate,
+};
+use crate::storage;
+
+const CHECKPOINT_FILE: &str = "checkpoint.json";
+
+struct Checkpoint {
+    started_unix_ms: u64,
+    profile: &'static str,
+    case: &'static str,
+    // 当前用例最近完成的第几次正式迭代与总次数
+    index: usize,
+    total: usize,
+    times_ms: Vec<f64>,
+    // 已完成用例的 p50
+    completed: Vec<(&'static str, f64)>,
+}
+
+impl Checkpoint {
+    fn json(&self) -> Value {
+        json!({
+            "started_unix_ms": self.started_unix_ms,
+            "profile": self.profile,
+            "case": self.case,
+            "index": self.index,
+            "total": self.total,
+            "times_ms": self.times_ms,
+            "completed": self
+                .completed
+                .iter()
+                .map(|(id, p50)| json!({ "id": id, "p50_ms": p50 }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+static CURRENT: Mutex<Option<Checkpoint>> = Mutex::new(None);
+
+fn current() -> std::sync::MutexGuard<'static, Option<Checkpoint>> {
+    CURRENT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn flush(checkpoint: &Checkpoint) {
+    let bytes = checkpoint.json().to_string();
+    if let Err(err) = storage::write_atomic(CHECKPOINT_FILE, bytes.as_bytes()) {
+        tracing::warn!("failed to write checkpoint: {}", err);
+    }
+}
+
+// 开跑时先写一份空记录，第一次正式迭代前中断也能留下痕迹
+pub fn begin(started_unix_ms: u64, profile: &'static str) {
+    let checkpoint = Checkpoint {
+        started_unix_ms,
+        profile,
+        case: "",
+        index: 0,
+        total: 0,
+        times_ms: Vec::new(),
+        completed: Vec::new(),
+    };
+    flush(&checkpoint);
+    *current() = Some(checkpoint);
+}
+
+// 只在正式迭代完成时写入，热身与块级更新不落盘
+pub fn on_progress(update: &ProgressUpdate) {
+    if !matches!(update.phase, BenchPhase::Measure)
+        || !matches!(update.status, BenchStepStatus::Finished)
+    {
+        return;
+    }
+    let mut guard = current();
+    let Some(checkpoint) = guard.as_mut() else {
+        return;
+    };
+    if checkpoint.case != update.bench_id {
+        checkpoint.case = update.bench_id;
+        checkpoint.times_ms.clear();
+    }
+    checkpoint.index = update.index;
+    checkpoint.total = update.total;
+    checkpoint.times_ms.push(benchmark::ms3(update.elapsed_ms));
+    flush(checkpoint);
+}
+
+pub fn on_case(case: &BenchCaseResult) {
+    if let Some(checkpoint) = current().as_mut()
+        && case.outcome == CaseOutcome::Ok
+    {
+        checkpoint
+            .completed
+            .push((case.id, benchmark::ms3(case.stats.p50)));
+    }
+}
+
+pub fn finish() {
+    *current() = None;
+    if let Err(err) = storage::remove(CHECKPOINT_FILE) {
+        tracing::warn!("failed to remove checkpoint: {}", err);
+    }
+}
+
+pub struct Interrupted {
+    pub started_unix_ms: u64,
+    pub profile: String,
+    pub case: String,
+    pub index: usize,
+    pub total: usize,
+    pub times_ms: Vec<f64>,
+    pub completed: Vec<(String, f64)>,
+}
+
+impl Interrupted {
+    fn from_json(value: &Value) -> Option<Self> {
+        let text = |key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+        let count = |key: &str| value.get(key).and_then(Value::as_u64).map(|v| v as usize);
+        Some(Interrupted {
+            started_unix_ms: value.get("started_unix_ms")?.as_u64()?,
+            profile: text("profile")?,
+            case: text("case")?,
+            index: count("index")?,
+            total: count("total")?,
+            times_ms: value
+                .get("times_ms")?
+                .as_array()?
+                .iter()
+                .filter_map(Value::as_f64)
+                .collect(),
+            completed: value
+                .get("completed")?
+                .as_array()?
+                .iter()
+                .filter_map(|entry| {
+                    let id = entry.get("id")?.as_str()?.to_string();
+                    Some((id, entry.get("p50_ms")?.as_f64()?))
+                })
+                .collect(),
+        })
+    }
+
+    // 当前用例已有样本的 (min, p50, max)
+    pub fn partial_stats(&self) -> Option<(f64, f64, f64)> {
+        let mut sorted = self.times_ms.clone();
+        sorted.sort_by(f64::total_cmp);
+        Some((
+            *sorted.first()?,
+            benchmark::median(&sorted),
+            *sorted.last()?,
+        ))
+    }
+}
+
+// 读出上次留下的记录并删除，每次中断只提示一次
+pub fn take_interrupted() -> Option<Interrupted> {
+    let bytes = storage::read(CHECKPOINT_FILE).ok()?;
+    if let Err(err) = storage::remove(CHECKPOINT_FILE) {
+        tracing::warn!("failed to remove checkpoint: {}", err);
+    }
+    let interrupted = serde_json::from_slice::<Value>(&bytes)
+        .ok()
+        .and_then(|value| Interrupted::from_json(&value));
+    if interrupted.is_none() {
+        tracing::warn!("ignoring unreadable {}", CHECKPOINT_FILE);
+    }
+    interrupted
+}
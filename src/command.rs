@@ -0,0 +1,222 @@
+// 宿主命令路由：PluginMessage 的 payload 是带 cmd 字段的 JSON 时按命令执行，
+// 结构化的应答经 on_event 的返回值交回宿主，宿主不经界面也能驱动整个插件。
+// 请求里的 id 会原样带回，方便宿主对应请求与应答
+use serde_json::{Value, json};
+
+use crate::benchmark;
+use crate::lifetime;
+use crate::report::csv;
+use crate::run_queue::{self, PROFILES};
+use crate::ui;
+
+pub const COMMANDS: [&str; 17] = [
+    "help",
+    "state",
+    "run",
+    "get_result",
+    "get_summary",
+    "get_history",
+    "export",
+    "diff",
+    "register_case",
+    "unregister_case",
+    "enqueue",
+    "dequeue",
+    "get_queue",
+    "run_queue",
+    "summarize",
+    "export_bundle",
+    "import_bundle",
+];
+const FORMATS: [&str; 4] = ["json", "csv", "summary", "diff"];
+
+type Reply = Result<Value, String>;
+
+fn parse_json(text: &str) -> Reply {
+    serde_json::from_str(text).map_err(|err| format!("结果 JSON 无法解析: {}", err))
+}
+
+fn last_result() -> Result<String, String> {
+    ui::shared_result_json().ok_or_else(|| "还没有测试结果".to_string())
+}
+
+fn run(request: &Value) -> Reply {
+    let profile = run_queue::profile(
+        request
+            .get("profile")
+            .and_then(Value::as_str)
+            .unwrap_or("standard"),
+    )?;
+    if !ui::run_with(Some(profile), |config| run_queue::apply(profile, config)) {
+        return Err("busy: 已有测试在运行".to_string());
+    }
+    // 测试在本次调用里同步跑完，这里已经有结果
+    Ok(json!({
+        "state": ui::status_json(),
+        "result": parse_json(&last_result()?)?,
+    }))
+}
+
+// 其他插件不能链接本插件的代码，要复用统计时把样本经这条命令发来
+fn summarize(request: &Value) -> Reply {
+    let numbers = |key: &str| -> Result<Vec<f64>, String> {
+        match request.get(key) {
+            None => Ok(Vec::new()),
+            Some(value) => value
+                .as_array()
+                .and_then(|values| values.iter().map(Value::as_f64).collect())
+                .ok_or_else(|| format!("{} 须为数字数组", key)),
+        }
+    };
+    let samples = numbers("samples")?;
+    if samples.is_empty() {
+        return Err("缺少 samples".to_string());
+    }
+    let stats = benchmark::summarize(&samples, &numbers("percentiles")?);
+    Ok(json!({ "count": samples.len(), "stats": stats.json() }))
+}
+
+fn queue_json() -> Value {
+    json!({ "queue": run_queue::entries(), "max": run_queue::MAX_QUEUE })
+}
+
+fn export(request: &Value) -> Reply {
+    let format = request
+        .get("format")
+        .and_then(Value::as_str)
+        .unwrap_or("json");
+    let content = match format {
+        "json" => last_result()?,
+        "csv" => csv::results_csv(&parse_json(&last_result()?)?)
+            .ok_or_else(|| "该结果没有逐用例数据，无法导出 CSV".to_string())?,
+        "summary" => ui::summary().ok_or_else(|| "只有标准测试有摘要".to_string())?,
+        "diff" => ui::changelog(None)?,
+        other => {
+            return Err(format!(
+                "未知的 format: {}，可选 {}",
+                other,
+                FORMATS.join(" / ")
+            ));
+        }
+    };
+    Ok(json!({ "format": format, "content": content }))
+}
+
+fn dispatch(cmd: &str, request: &Value) -> Reply {
+    match cmd {
+        "help" => Ok(json!({
+            "commands": COMMANDS,
+            "profiles": PROFILES,
+            "formats": FORMATS,
+            "cases": benchmark::all_cases()
+                .iter()
+                .map(|case| case.id)
+                .collect::<Vec<_>>(),
+            "custom_cases": benchmark::registry_json(),
+            "plugin_version": env!("CARGO_PKG_VERSION"),
+        })),
+        "state" => Ok(ui::status_json()),
+        "run" => run(request),
+        "get_result" => parse_json(&last_result()?),
+        "get_summary" => ui::summary()
+            .ok_or_else(|| "只有标准测试有摘要".to_string())
+            .and_then(|summary| parse_json(&summary)),
+        "get_history" => Ok(lifetime::load().json()),
+        "export" => export(request),
+        // baseline 可以直接给一份结果 JSON，缺省时用界面上设定的对比基准
+        "diff" => {
+            let baseline = request.get("baseline").map(Value::to_string);
+            Ok(json!({ "text": ui::changelog(baseline.as_deref())? }))
+        }
+        // 请求本身就是工作负载描述，字段见 benchmark::custom；用例名放在 case 里，id 仍用来对应应答
+        "register_case" => benchmark::register_case(request)
+            .map(|case| json!({ "case": case.id, "custom_cases": benchmark::registry_json() })),
+        "unregister_case" => {
+            let id = request
+                .get("case")
+                .and_then(Value::as_str)
+                .ok_or("缺少 case")?;
+            if benchmark::unregister_case(id) {
+                Ok(json!({ "custom_cases": benchmark::registry_json() }))
+            } else {
+                Err(format!("没有登记过 {}", id))
+            }
+        }
+        "enqueue" => {
+            let profile = request
+                .get("profile")
+                .and_then(Value::as_str)
+                .unwrap_or("standard");
+            run_queue::enqueue(profile)?;
+            ui::refresh();
+            Ok(queue_json())
+        }
+        // index 从 0 开始
+        "dequeue" => {
+            let index = request
+                .get("index")
+                .and_then(Value::as_u64)
+                .ok_or("缺少 index")?;
+            if !run_queue::remove(index as usize) {
+                return Err(format!("队列里没有第 {} 项", index));
+            }
+            ui::refresh();
+            Ok(queue_json())
+        }
+        "get_queue" => Ok(queue_json()),
+        // 同步跑完整个队列，结果为最后一次运行的结果
+        "run_queue" => {
+            let ran = ui::run_queue()?;
+            Ok(json!({
+                "ran": ran,
+                "queue": run_queue::entries(),
+                "state": ui::status_json(),
+                "result": parse_json(&last_result()?)?,
+            }))
+        }
+        "summarize" => summarize(request),
+        // indices 为历史结果的下标（从 0 开始，旧的在前），缺省时取界面上勾选的
+        "export_bundle" => {
+            let indices = match request.get("indices") {
+                None => None,
+                Some(value) => Some(
+                    value
+                        .as_array()
+                        .and_then(|values| {
+                            values
+                                .iter()
+                                .map(|v| v.as_u64().map(|i| i as usize))
+                                .collect::<Option<Vec<_>>>()
+                        })
+                        .ok_or("indices 须为下标数组")?,
+                ),
+            };
+            let content = ui::bundle(indices.as_deref())?;
+            Ok(json!({ "content": parse_json(&content)? }))
+        }
+        "import_bundle" => {
+            let bundle = request.get("bundle").ok_or("缺少 bundle")?;
+            let (count, added) = ui::import_bundle(&bundle.to_string())?;
+            Ok(json!({ "count": count, "added": added }))
+        }
+        other => Err(format!("未知命令: {}", other)),
+    }
+}
+
+// payload 不是命令（不是 JSON 对象或没有 cmd 字段）时返回 None，交给其他处理者
+pub fn handle(payload: &str) -> Option<String> {
+    let request: Value = serde_json::from_str(payload).ok()?;
+    let cmd = request.get("cmd")?.as_str()?;
+    tracing::info!("host command: {}", cmd);
+    let mut reply = match dispatch(cmd, &request) {
+        Ok(result) => json!({ "ok": true, "cmd": cmd, "result": result }),
+        Err(error) => {
+            tracing::warn!("host command {} failed: {}", cmd, error);
+            json!({ "ok": false, "cmd": cmd, "error": error })
+        }
+    };
+    if let Some(id) = request.get("id") {
+        reply["id"] = id.clone();
+    }
+    Some(reply.to_string())
+}
@@ -0,0 +1,93 @@
+// 运行环境信息：宿主系统与插件自身的启动耗时，写入结果 JSON 的 env 段并显示在「关于」页
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use serde_json::{Value, json};
+
+use crate::astrobox::psys_host::os;
+use crate::benchmark::{self, ms3, ms6};
+use crate::event_probe;
+use crate::timestamp;
+
+pub struct HostInfo {
+    pub platform: String,
+    pub arch: String,
+    pub os_version: String,
+    pub language: String,
+    // 系统语言区域，如 zh-CN，用来推测时区
+    pub locale: String,
+}
+
+static INIT_AT: OnceLock<Instant> = OnceLock::new();
+// on_load 到第一次 render_main_ui 的耗时
+static STARTUP_MS: OnceLock<f64> = OnceLock::new();
+static HOST_INFO: OnceLock<HostInfo> = OnceLock::new();
+
+// 应在 on_load 的第一行调用
+pub fn mark_init() {
+    INIT_AT.get_or_init(Instant::now);
+}
+
+pub fn mark_first_render() {
+    if let Some(init) = INIT_AT.get() {
+        STARTUP_MS.get_or_init(|| init.elapsed().as_secs_f64() * 1000.0);
+    }
+}
+
+pub fn startup_ms() -> Option<f64> {
+    STARTUP_MS.get().copied()
+}
+
+pub fn collect_host_info() -> &'static HostInfo {
+    HOST_INFO.get_or_init(|| {
+        wit_bindgen::block_on(async {
+            HostInfo {
+                platform: os::platform().await,
+                arch: os::arch().await,
+                os_version: os::version().await,
+                language: os::astrobox_language().await,
+                locale: os::locale().await,
+            }
+        })
+    })
+}
+
+// 未在插件环境中运行（如原生单元测试）时为 None
+pub fn host_info() -> Option<&'static HostInfo> {
+    HOST_INFO.get()
+}
+
+// 由宿主平台推断设备类别，决定默认跑哪些用例、各跑几轮
+pub fn device_class() -> benchmark::DeviceClass {
+    host_info().map_or(benchmark::DeviceClass::Unknown, |h| {
+        benchmark::DeviceClass::from_platform(&h.platform)
+    })
+}
+
+// 宿主不提供时区，按系统语言区域推测 UTC 偏移，用户可在设置里改
+pub fn guessed_utc_offset() -> i32 {
+    host_info().map_or(0, |h| timestamp::guess_offset(&h.locale))
+}
+
+pub fn json() -> Value {
+    let host = host_info();
+    json!({
+        "plugin_version": env!("CARGO_PKG_VERSION"),
+        "platform": host.map(|h| h.platform.as_str()),
+        "arch": host.map(|h| h.arch.as_str()),
+        "os_version": host.map(|h| h.os_version.as_str()),
+        "language": host.map(|h| h.language.as_str()),
+        "locale": host.map(|h| h.locale.as_str()),
+        // WASI 下多半取不到，为 null
+        "cores": std::thread::available_parallelism().ok().map(|n| n.get()),
+        "startup_ms": startup_ms().map(ms3),
+        "event_rtt_ms": event_probe::median_ms().map(ms3),
+        "timer": benchmark::measured_timer_probe().map(|timer| json!({
+            "resolution_ms": ms6(timer.resolution_ms),
+            "sleep_1ms_ms": ms3(timer.sleep_1ms_ms),
+            "yield_ms": ms6(timer.yield_ms),
+            "now_overhead_ms": ms6(timer.now_overhead_ms),
+            "coarse": timer.is_coarse(),
+        })),
+    })
+}
@@ -0,0 +1,99 @@
+// 宿主事件往返延迟：经 event::send_event 发出 ping，宿主把消息回投到 on_event 时记为 pong，
+// 收到 pong 后再发下一个。测试进行中回投的消息要排在套件的进度渲染之后，测得的延迟不具代表性，
+// 所以这个探测独立于套件、由用户单独触发
+use std::sync::Mutex;
+use std::time::Instant;
+
+use serde_json::Value;
+
+use crate::astrobox::psys_host::event;
+use crate::benchmark::median;
+
+pub const PING_EVENT: &str = "benchmark_ping";
+pub const PINGS: usize = 16;
+const TAG: &str = "bench-ping-";
+
+pub enum ProbeStatus {
+    Idle,
+    // 已收到的 pong 数
+    Waiting(usize),
+    Done(f64),
+}
+
+struct ProbeState {
+    seq: u32,
+    sent_at: Option<Instant>,
+    samples: Vec<f64>,
+}
+
+static PROBE: Mutex<ProbeState> = Mutex::new(ProbeState {
+    seq: 0,
+    sent_at: None,
+    samples: Vec::new(),
+});
+
+fn probe() -> std::sync::MutexGuard<'static, ProbeState> {
+    PROBE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn send(state: &mut ProbeState) {
+    state.seq += 1;
+    state.sent_at = Some(Instant::now());
+    event::send_event(PING_EVENT, &format!("{}{}", TAG, state.seq));
+}
+
+// 每轮探测的序号从 1 重新开始
+pub fn start() {
+    let mut state = probe();
+    state.seq = 0;
+    state.samples.clear();
+    send(&mut state);
+}
+
+// 宿主可能原样回投，也可能包成 JSON 字符串或带 payload 字段的对象；不是 ping 时返回 None
+fn pong_seq(payload: &str) -> Option<u32> {
+    let text = match serde_json::from_str::<Value>(payload) {
+        Ok(Value::String(text)) => text,
+        Ok(Value::Object(fields)) => fields.get("payload")?.as_str()?.to_string(),
+        _ => payload.trim().to_string(),
+    };
+    text.strip_prefix(TAG)?.parse().ok()
+}
+
+// 返回 true 表示 payload 是本探测的 pong。序号必须与最近一次 ping 完全相同，
+// 迟到或重复的回投不算
+pub fn on_message(payload: &str) -> bool {
+    let mut state = probe();
+    let seq = state.seq;
+    let Some(sent_at) = state.sent_at.filter(|_| pong_seq(payload) == Some(seq)) else {
+        return false;
+    };
+    state.sent_at = None;
+    state.samples.push(sent_at.elapsed().as_secs_f64() * 1000.0);
+    if state.samples.len() < PINGS {
+        send(&mut state);
+    }
+    true
+}
+
+pub fn status() -> ProbeStatus {
+    let state = probe();
+    if state.samples.len() >= PINGS {
+        let mut sorted = state.samples.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        ProbeStatus::Done(median(&sorted))
+    } else if state.sent_at.is_some() {
+        ProbeStatus::Waiting(state.samples.len())
+    } else {
+        ProbeStatus::Idle
+    }
+}
+
+pub fn median_ms() -> Option<f64> {
+    match status() {
+        ProbeStatus::Done(ms) => Some(ms),
+        ProbeStatus::Idle | ProbeStatus::Waiting(_) => None,
+    }
+}
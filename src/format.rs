@@ -0,0 +1,58 @@
+// 面向界面的数字与时长格式化，JSON 输出不经过这里
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    Zh,
+    En,
+}
+
+impl Locale {
+    pub fn from_tag(tag: &str) -> Self {
+        if tag.trim().to_ascii_lowercase().starts_with("zh") {
+            Locale::Zh
+        } else {
+            Locale::En
+        }
+    }
+}
+
+fn trim_decimal(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+// 1234.5 -> "1.234 s"，87.2 -> "87 ms"，3.21 -> "3.21 ms"
+pub fn duration_ms(ms: f64) -> String {
+    if !ms.is_finite() {
+        "-".to_string()
+    } else if ms >= 1000.0 {
+        format!("{:.3} s", ms / 1000.0)
+    } else if ms >= 10.0 {
+        format!("{:.0} ms", ms)
+    } else {
+        format!("{:.2} ms", ms)
+    }
+}
+
+// 300_000_000 -> "3亿" / "300M"
+pub fn count(n: u64, locale: Locale) -> String {
+    let v = n as f64;
+    let units: &[(f64, &str)] = match locale {
+        Locale::Zh => &[(1e8, "亿"), (1e4, "万")],
+        Locale::En => &[(1e9, "B"), (1e6, "M"), (1e3, "K")],
+    };
+    units
+        .iter()
+        .find(|(scale, _)| v >= *scale)
+        .map(|(scale, suffix)| format!("{}{}", trim_decimal(v / scale, 2), suffix))
+        .unwrap_or_else(|| n.to_string())
+}
+
+// 每秒操作数，按 count 的量级规则缩写
+pub fn rate(ops_per_sec: f64, locale: Locale) -> String {
+    format!("{} ops/s", count(ops_per_sec.max(0.0) as u64, locale))
+}
@@ -0,0 +1,102 @@
+// 长时间压力运行的心跳：持续负载期间每隔 INTERVAL_SECS 秒打一行简短日志（第几轮、当前吞吐、温度），
+// 开启 heartbeat_log 时同时追加到数据目录的 heartbeat.log。整夜运行中途被杀掉时，
+// 日志与文件里的最后一行就是它停下的位置。文件在下一次持续测试开跑时清空，正常结束时保留
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::benchmark::{BenchStepStatus, ProgressUpdate};
+use crate::{format, storage, timestamp};
+
+pub const INTERVAL_SECS: u64 = 60;
+pub const HEARTBEAT_FILE: &str = "heartbeat.log";
+
+struct Heartbeat {
+    started: Instant,
+    // 上一次心跳的时间，None 表示还没打过
+    last: Option<Instant>,
+    to_storage: bool,
+}
+
+static CURRENT: Mutex<Option<Heartbeat>> = Mutex::new(None);
+
+fn current() -> std::sync::MutexGuard<'static, Option<Heartbeat>> {
+    CURRENT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn write_line(to_storage: bool, line: &str) {
+    tracing::info!("{}", line);
+    if !to_storage {
+        return;
+    }
+    let text = format!(
+        "{} {}\n",
+        timestamp::iso8601_utc(timestamp::now_unix_ms()),
+        line
+    );
+    if let Err(err) = storage::append(HEARTBEAT_FILE, text.as_bytes()) {
+        tracing::warn!("failed to append heartbeat: {}", err);
+    }
+}
+
+pub fn begin(minutes: u32, to_storage: bool) {
+    if to_storage && let Err(err) = storage::write(HEARTBEAT_FILE, b"") {
+        tracing::warn!("failed to reset {}: {}", HEARTBEAT_FILE, err);
+    }
+    write_line(
+        to_storage,
+        &format!(
+            "heartbeat start minutes={} interval_s={}",
+            minutes, INTERVAL_SECS
+        ),
+    );
+    *current() = Some(Heartbeat {
+        started: Instant::now(),
+        last: None,
+        to_storage,
+    });
+}
+
+// 每个采样窗口结束时调用；第一个窗口结束时立即打一次，之后至少间隔 INTERVAL_SECS 秒
+pub fn on_progress(update: &ProgressUpdate) {
+    if !matches!(update.status, BenchStepStatus::Finished) {
+        return;
+    }
+    let mut guard = current();
+    let Some(heartbeat) = guard.as_mut() else {
+        return;
+    };
+    if heartbeat
+        .last
+        .is_some_and(|last| last.elapsed().as_secs() < INTERVAL_SECS)
+    {
+        return;
+    }
+    heartbeat.last = Some(Instant::now());
+    // 宿主没有温度传感器接口，temp 恒为 n/a，字段留着以便宿主日后提供时直接填入
+    let line = format!(
+        "heartbeat pass={}/{} ops_per_sec={} temp=n/a elapsed_s={}",
+        update.index,
+        update.total,
+        update
+            .ops_per_sec
+            .map_or("n/a".to_string(), |rate| format!("{:.0}", rate)),
+        heartbeat.started.elapsed().as_secs()
+    );
+    write_line(heartbeat.to_storage, &line);
+}
+
+pub fn finish(cancelled: bool) {
+    let Some(heartbeat) = current().take() else {
+        return;
+    };
+    write_line(
+        heartbeat.to_storage,
+        &format!(
+            "heartbeat end {} after {}",
+            if cancelled { "cancelled" } else { "done" },
+            format::duration_ms(heartbeat.started.elapsed().as_secs_f64() * 1000.0)
+        ),
+    );
+}
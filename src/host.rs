@@ -0,0 +1,27 @@
+// benchmark::Host 的插件实现：把套件对宿主的需求接到 psys_host
+use serde_json::Value;
+
+use crate::astrobox::psys_host::ui;
+use crate::benchmark::Host;
+use crate::env;
+
+pub struct PluginHost;
+
+impl Host for PluginHost {
+    fn render_tree(&self, target: &str, size: u32, round: u64) {
+        let tree = (0..size).fold(
+            ui::Element::new(ui::ElementType::Div, None)
+                .flex()
+                .flex_direction(ui::FlexDirection::Column),
+            |root, i| {
+                let label = format!("UI 性能 {} · {}", round, i);
+                root.child(ui::Element::new(ui::ElementType::P, Some(label.as_str())).size(12))
+            },
+        );
+        ui::render(target, tree);
+    }
+
+    fn env_json(&self) -> Value {
+        env::json()
+    }
+}
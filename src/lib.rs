@@ -8,6 +8,24 @@ use crate::exports::astrobox::psys_plugin::{
 pub mod logger;
 pub mod ui;
 pub mod benchmark;
+pub mod format;
+pub mod report;
+pub mod env;
+pub mod storage;
+pub mod event_probe;
+pub mod progress_stream;
+pub mod wearable;
+pub mod host;
+pub mod lifetime;
+pub mod timestamp;
+pub mod command;
+pub mod checkpoint;
+pub mod capabilities;
+pub mod run_queue;
+pub mod heartbeat;
+pub mod build_info;
+pub mod status_card;
+pub mod result_history;
 
 wit_bindgen::generate!({
     path: "wit",
@@ -21,9 +39,16 @@ impl event::Guest for MyPlugin {
     #[allow(async_fn_in_trait)]
     fn on_event(event_type: EventType, event_payload: _rt::String) -> FutureReader<String> {
         let (writer, reader) = wit_future::new::<String>(|| "".to_string());
+        let mut reply = String::new();
 
         match event_type {
-            EventType::PluginMessage => {}
+            EventType::PluginMessage => {
+                if event_probe::on_message(&event_payload) {
+                    ui::refresh();
+                } else if let Some(response) = command::handle(&event_payload) {
+                    reply = response;
+                }
+            }
             EventType::InterconnectMessage => {}
             EventType::DeviceAction => {}
             EventType::ProviderAction => {}
@@ -34,7 +59,7 @@ impl event::Guest for MyPlugin {
         tracing::info!("event_payload: {}", event_payload);
 
         wit_bindgen::spawn(async move {
-            let _ = writer.write("".to_string()).await;
+            let _ = writer.write(reply).await;
         });
 
         reader
@@ -43,11 +68,11 @@ impl event::Guest for MyPlugin {
     fn on_ui_event(
         event_id: _rt::String,
         event: event::Event,
-        _event_payload: _rt::String,
+        event_payload: _rt::String,
     ) -> wit_bindgen::rt::async_support::FutureReader<_rt::String> {
         let (writer, reader) = wit_future::new::<String>(|| "".to_string());
 
-        ui::ui_event_processor(event, &event_id);
+        ui::ui_event_processor(event, &event_id, &event_payload);
 
         wit_bindgen::spawn(async move {
             let _ = writer.write("".to_string()).await;
@@ -68,9 +93,11 @@ impl event::Guest for MyPlugin {
         reader
     }
 
-    fn on_card_render(_card_id: _rt::String) -> wit_bindgen::rt::async_support::FutureReader<()> {
+    fn on_card_render(card_id: _rt::String) -> wit_bindgen::rt::async_support::FutureReader<()> {
         let (writer, reader) = wit_future::new::<()>(|| ());
 
+        status_card::render(&card_id);
+
         wit_bindgen::spawn(async move {
             let _ = writer.write(()).await;
         });
@@ -81,9 +108,16 @@ impl event::Guest for MyPlugin {
 
 impl lifecycle::Guest for MyPlugin {
     #[allow(async_fn_in_trait)]
-    fn on_load() -> () {
+    fn on_load() {
+        env::mark_init();
         logger::init();
         tracing::info!("Hello AstroBox V2 Plugin!");
+
+        let host = env::collect_host_info();
+        // 计时器探测约 10 ms，加载时先做掉，免得算进第一次开跑
+        benchmark::timer_probe();
+        ui::set_locale(format::Locale::from_tag(&host.language));
+        status_card::register();
     }
 }
 
@@ -0,0 +1,263 @@
+// 跨运行累计的统计：总运行次数、总耗时与每个用例历次 p50，存放在插件数据目录。
+// 首次运行的条件快照作为基准，之后条件明显不同的运行在历史里单独标记，比较时不混为一谈。
+// 每次运行结束后整体读-改-写一次，经临时文件改名替换，崩溃或中途失败不会留下半个文件
+use std::sync::Mutex;
+
+use serde_json::{Map, Value, json};
+
+use crate::benchmark::{self, BenchCaseResult, CaseOutcome, Conditions};
+use crate::storage;
+
+const LIFETIME_FILE: &str = "lifetime.json";
+const LIFETIME_VERSION: u64 = 1;
+// 每个用例保留最近的 p50 个数（中位数按这些样本计算），运行记录也保留这么多条
+const MAX_HISTORY: usize = 200;
+
+// 同一进程内的读-改-写串行执行
+static LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Default)]
+pub struct LifetimeStats {
+    pub total_runs: u64,
+    pub total_bench_ms: f64,
+    // 首次与最近一次运行的开始时间（UTC 毫秒）
+    pub first_run_unix_ms: Option<u64>,
+    pub last_run_unix_ms: Option<u64>,
+    // 第一次带条件快照的运行开跑前的条件
+    pub baseline_conditions: Option<Conditions>,
+    // 按首次出现的顺序
+    pub cases: Vec<CaseHistory>,
+    // 最近的运行记录，旧文件里没有
+    pub runs: Vec<RunRecord>,
+}
+
+pub struct RunRecord {
+    pub started_unix_ms: u64,
+    pub elapsed_ms: f64,
+    // 运行时的 profile 名，即 run_queue::PROFILES 之一
+    pub profile: String,
+}
+
+impl RunRecord {
+    fn from_json(value: &Value) -> Option<Self> {
+        Some(RunRecord {
+            started_unix_ms: value.get("started_unix_ms")?.as_u64()?,
+            elapsed_ms: value.get("elapsed_ms")?.as_f64()?,
+            profile: value.get("profile")?.as_str()?.to_string(),
+        })
+    }
+
+    fn json(&self) -> Value {
+        json!({
+            "started_unix_ms": self.started_unix_ms,
+            "elapsed_ms": benchmark::ms3(self.elapsed_ms),
+            "profile": self.profile,
+        })
+    }
+}
+
+pub struct CaseHistory {
+    pub id: String,
+    pub entries: Vec<HistoryEntry>,
+}
+
+pub struct HistoryEntry {
+    pub p50_ms: f64,
+    // 该次运行开跑前的条件与基准相比变化明显
+    pub conditions_changed: bool,
+}
+
+impl HistoryEntry {
+    // 旧文件里的条目只有数字
+    fn from_json(value: &Value) -> Option<Self> {
+        if let Some(p50_ms) = value.as_f64() {
+            return Some(HistoryEntry {
+                p50_ms,
+                conditions_changed: false,
+            });
+        }
+        Some(HistoryEntry {
+            p50_ms: value.get("p50_ms")?.as_f64()?,
+            conditions_changed: value
+                .get("conditions_changed")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+        })
+    }
+
+    fn json(&self) -> Value {
+        if self.conditions_changed {
+            json!({ "p50_ms": self.p50_ms, "conditions_changed": true })
+        } else {
+            json!(self.p50_ms)
+        }
+    }
+}
+
+impl CaseHistory {
+    fn p50s(&self) -> impl Iterator<Item = f64> + '_ {
+        self.entries.iter().map(|entry| entry.p50_ms)
+    }
+
+    pub fn best(&self) -> f64 {
+        self.p50s().fold(f64::INFINITY, f64::min)
+    }
+
+    pub fn worst(&self) -> f64 {
+        self.p50s().fold(0.0, f64::max)
+    }
+
+    pub fn median(&self) -> f64 {
+        let mut sorted: Vec<f64> = self.p50s().collect();
+        sorted.sort_by(f64::total_cmp);
+        benchmark::median(&sorted)
+    }
+
+    pub fn changed_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.conditions_changed)
+            .count()
+    }
+}
+
+impl LifetimeStats {
+    fn from_json(value: &Value) -> Option<Self> {
+        if value.get("version")?.as_u64()? != LIFETIME_VERSION {
+            return None;
+        }
+        let cases = value
+            .get("cases")?
+            .as_object()?
+            .iter()
+            .map(|(id, history)| CaseHistory {
+                id: id.clone(),
+                entries: history
+                    .as_array()
+                    .map(|values| values.iter().filter_map(HistoryEntry::from_json).collect())
+                    .unwrap_or_default(),
+            })
+            .collect();
+        Some(Self {
+            total_runs: value.get("total_runs")?.as_u64()?,
+            total_bench_ms: value.get("total_bench_ms")?.as_f64()?,
+            first_run_unix_ms: value.get("first_run_unix_ms").and_then(Value::as_u64),
+            last_run_unix_ms: value.get("last_run_unix_ms").and_then(Value::as_u64),
+            baseline_conditions: value
+                .get("baseline_conditions")
+                .and_then(Conditions::from_json),
+            cases,
+            runs: value
+                .get("runs")
+                .and_then(Value::as_array)
+                .map(|values| values.iter().filter_map(RunRecord::from_json).collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    // 按 profile 统计运行次数，按首次出现的顺序
+    pub fn runs_by_profile(&self) -> Vec<(&str, usize)> {
+        let mut counts: Vec<(&str, usize)> = Vec::new();
+        for run in &self.runs {
+            match counts.iter_mut().find(|(name, _)| *name == run.profile) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((run.profile.as_str(), 1)),
+            }
+        }
+        counts
+    }
+
+    pub fn json(&self) -> Value {
+        let cases: Map<String, Value> = self
+            .cases
+            .iter()
+            .map(|case| {
+                let entries: Vec<Value> = case.entries.iter().map(HistoryEntry::json).collect();
+                (case.id.clone(), json!(entries))
+            })
+            .collect();
+        json!({
+            "version": LIFETIME_VERSION,
+            "total_runs": self.total_runs,
+            "total_bench_ms": benchmark::ms3(self.total_bench_ms),
+            "first_run_unix_ms": self.first_run_unix_ms,
+            "last_run_unix_ms": self.last_run_unix_ms,
+            "baseline_conditions": self.baseline_conditions.as_ref().map(Conditions::json),
+            "cases": cases,
+            "runs": self.runs.iter().map(RunRecord::json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+// 文件不存在或无法解析时从零开始
+pub fn load() -> LifetimeStats {
+    let Ok(bytes) = storage::read(LIFETIME_FILE) else {
+        return LifetimeStats::default();
+    };
+    match serde_json::from_slice::<Value>(&bytes)
+        .ok()
+        .and_then(|value| LifetimeStats::from_json(&value))
+    {
+        Some(stats) => stats,
+        None => {
+            tracing::warn!("ignoring unreadable {}", LIFETIME_FILE);
+            LifetimeStats::default()
+        }
+    }
+}
+
+// 把一次运行计入累计统计；只有 Ok 的用例贡献 p50。conditions 为开跑前的条件快照，
+// profile 记在运行记录里
+pub fn record(
+    started_unix_ms: u64,
+    elapsed_ms: f64,
+    profile: &str,
+    cases: &[BenchCaseResult],
+    conditions: Option<&Conditions>,
+) -> Result<LifetimeStats, String> {
+    let _guard = LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut stats = load();
+    stats.total_runs += 1;
+    stats.first_run_unix_ms.get_or_insert(started_unix_ms);
+    stats.last_run_unix_ms = Some(started_unix_ms);
+    stats.total_bench_ms += elapsed_ms;
+    stats.runs.push(RunRecord {
+        started_unix_ms,
+        elapsed_ms,
+        profile: profile.to_string(),
+    });
+    if stats.runs.len() > MAX_HISTORY {
+        stats.runs.remove(0);
+    }
+    let conditions_changed = match (&stats.baseline_conditions, conditions) {
+        (Some(baseline), Some(current)) => baseline.compare(current).significant,
+        (None, Some(current)) => {
+            stats.baseline_conditions = Some(*current);
+            false
+        }
+        (_, None) => false,
+    };
+    for case in cases.iter().filter(|case| case.outcome == CaseOutcome::Ok) {
+        let index = match stats.cases.iter().position(|h| h.id == case.id) {
+            Some(index) => index,
+            None => {
+                stats.cases.push(CaseHistory {
+                    id: case.id.to_string(),
+                    entries: Vec::new(),
+                });
+                stats.cases.len() - 1
+            }
+        };
+        let history = &mut stats.cases[index].entries;
+        history.push(HistoryEntry {
+            p50_ms: benchmark::ms3(case.stats.p50),
+            conditions_changed,
+        });
+        if history.len() > MAX_HISTORY {
+            history.remove(0);
+        }
+    }
+    let bytes = serde_json::to_vec(&stats.json()).map_err(|err| err.to_string())?;
+    storage::write_atomic(LIFETIME_FILE, &bytes).map_err(|err| err.to_string())?;
+    Ok(stats)
+}
@@ -0,0 +1,51 @@
+// 把 ProgressUpdate 转成 JSON 经宿主事件转发，宿主或配对设备可以自行绘制进度。
+// 块级的 Running 更新按 MIN_INTERVAL 节流，开始/结束每步都会发出
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::astrobox::psys_host::event;
+use crate::benchmark::{BenchStepStatus, PROGRESS_INTERVAL, ProgressUpdate};
+
+pub const PROGRESS_EVENT: &str = "benchmark_progress";
+const MIN_INTERVAL: Duration = PROGRESS_INTERVAL;
+
+static LAST_SENT: Mutex<Option<Instant>> = Mutex::new(None);
+
+fn last_sent() -> std::sync::MutexGuard<'static, Option<Instant>> {
+    LAST_SENT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// 每次测试开始前调用，保证第一条更新不被节流
+pub fn reset() {
+    *last_sent() = None;
+}
+
+pub fn emit(update: &ProgressUpdate) {
+    let now = Instant::now();
+    {
+        let mut last = last_sent();
+        let throttled = matches!(update.status, BenchStepStatus::Running)
+            && last.is_some_and(|at| now.duration_since(at) < MIN_INTERVAL);
+        if throttled {
+            return;
+        }
+        *last = Some(now);
+    }
+
+    let payload = serde_json::json!({
+        "case": update.bench_id,
+        "phase": update.phase.as_str(),
+        "status": update.status.as_str(),
+        "index": update.index,
+        "total": update.total,
+        "completed_steps": update.completed_steps,
+        "total_steps": update.total_steps,
+        "chunk_index": update.chunk_index,
+        "chunk_total": update.chunk_total,
+        "elapsed_ms": update.elapsed_ms,
+        "ops_per_sec": update.ops_per_sec,
+    });
+    event::send_event(PROGRESS_EVENT, &payload.to_string());
+}
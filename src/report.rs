@@ -0,0 +1,5 @@
+pub mod bundle;
+pub mod csv;
+pub mod diff;
+pub mod share;
+pub mod text;
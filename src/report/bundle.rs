@@ -0,0 +1,56 @@
+// 多份结果的合集，供离线分析或在另一台设备上导入对比。合集是一个 JSON 数组，
+// 每项带导出端的套件版本、夹具版本与该次运行的 env，result 为完整的结果 JSON
+use serde_json::{Value, json};
+
+use super::share;
+use crate::benchmark;
+
+pub fn export(results: &[&Value], anonymize: bool) -> String {
+    let entries: Vec<Value> = results
+        .iter()
+        .map(|result| {
+            let mut result = (*result).clone();
+            if anonymize {
+                share::anonymize(&mut result);
+            }
+            json!({
+                "suite_version": env!("CARGO_PKG_VERSION"),
+                "fixture_version": benchmark::FIXTURE_VERSION,
+                "env": result.get("env").cloned().unwrap_or(Value::Null),
+                "result": result,
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&entries).unwrap_or_else(|_| "[]".to_string())
+}
+
+// 取出合集里的测试结果，标记为导入；任何一项不是测试结果（没有 results 数组也没有 profile）时整份拒绝
+pub fn import(text: &str) -> Result<Vec<Value>, String> {
+    let value: Value =
+        serde_json::from_str(text).map_err(|err| format!("不是有效的 JSON: {}", err))?;
+    let entries = value.as_array().ok_or("不是结果合集（应为 JSON 数组）")?;
+    if entries.is_empty() {
+        return Err("合集为空".to_string());
+    }
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let mut result = entry
+                .get("result")
+                .filter(|result| {
+                    result.get("results").is_some_and(Value::is_array)
+                        || result.get("profile").is_some_and(Value::is_string)
+                })
+                .cloned()
+                .ok_or_else(|| format!("第 {} 项不是测试结果", index + 1))?;
+            if result.get("env").is_none()
+                && let Some(env) = entry.get("env")
+            {
+                result["env"] = env.clone();
+            }
+            result["imported"] = Value::Bool(true);
+            Ok(result)
+        })
+        .collect()
+}
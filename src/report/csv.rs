@@ -0,0 +1,52 @@
+// 结果 JSON 转 CSV，一行一个用例，方便导入表格软件。只有标准测试有逐用例数据。
+// 耗时列固定为毫秒，结果按微秒输出时换算回来
+use serde_json::Value;
+
+use super::diff::time_ms;
+
+const HEADER: &str =
+    "id,outcome,min_ms,p50_ms,p95_ms,max_ms,mean_ms,stddev_ms,throughput,unit,digest_u64";
+
+// 目前的字段都不含逗号和引号，保险起见仍按 RFC 4180 转义
+fn quote(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn cell(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(text)) => quote(text),
+        Some(Value::Null) | None => String::new(),
+        Some(other) => other.to_string(),
+    }
+}
+
+// 结果里没有 results 数组（持续、A/B 测试）时返回 None
+pub fn results_csv(result: &Value) -> Option<String> {
+    let results = result.get("results")?.as_array()?;
+    let mut out = String::from(HEADER);
+    out.push('\n');
+    for entry in results {
+        let time = |key: &str| time_ms(entry, key).map_or(String::new(), |ms| ms.to_string());
+        let throughput = entry.get("throughput");
+        let row = [
+            cell(entry.get("id")),
+            cell(entry.get("outcome")),
+            time("min"),
+            time("p50"),
+            time("p95"),
+            time("max"),
+            time("mean"),
+            time("stddev"),
+            cell(throughput.and_then(|t| t.get("value"))),
+            cell(throughput.and_then(|t| t.get("unit"))),
+            cell(entry.get("digest_u64")),
+        ];
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    Some(out)
+}
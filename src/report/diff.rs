@@ -0,0 +1,202 @@
+// 两份结果 JSON 的逐用例对比，输出“T1 p50 +3.1%, T2 p95 −12%”式的变更摘要。
+// 变化是否显著按两边各自的变异系数（stddev / mean）判断，旧版结果没有 stddev 时不做判断
+use serde_json::Value;
+
+// 变化超过两边合并变异系数的这么多倍才算显著
+pub const SIGNIFICANCE_CV_MULTIPLE: f64 = 2.0;
+
+pub struct MetricChange {
+    pub metric: &'static str,
+    // current / baseline - 1
+    pub change: f64,
+    // 耗时变大或吞吐变小
+    pub worse: bool,
+    // None 表示缺少 stddev，无法判断
+    pub significant: Option<bool>,
+}
+
+pub enum CaseDiff {
+    Compared {
+        id: String,
+        changes: Vec<MetricChange>,
+    },
+    OnlyCurrent(String),
+    OnlyBaseline(String),
+}
+
+pub struct ResultDiff {
+    pub baseline_started_at: Option<String>,
+    pub cases: Vec<CaseDiff>,
+}
+
+impl ResultDiff {
+    pub fn significant_count(&self) -> usize {
+        self.cases
+            .iter()
+            .map(|case| match case {
+                CaseDiff::Compared { changes, .. } => changes
+                    .iter()
+                    .filter(|c| c.significant == Some(true))
+                    .count(),
+                CaseDiff::OnlyCurrent(_) | CaseDiff::OnlyBaseline(_) => 0,
+            })
+            .sum()
+    }
+}
+
+// 只取完成的用例
+fn ok_cases(result: &Value) -> Vec<&Value> {
+    result
+        .get("results")
+        .and_then(Value::as_array)
+        .map(|results| {
+            results
+                .iter()
+                .filter(|entry| entry.get("outcome").and_then(Value::as_str) == Some("ok"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn id_of(entry: &Value) -> &str {
+    entry.get("id").and_then(Value::as_str).unwrap_or("")
+}
+
+// 统一换算为毫秒，结果可能按设置写成 time_us
+pub fn time_ms(entry: &Value, key: &str) -> Option<f64> {
+    if let Some(time) = entry.get("time_ms") {
+        return time.get(key)?.as_f64();
+    }
+    Some(entry.get("time_us")?.get(key)?.as_f64()? / 1000.0)
+}
+
+fn cv(entry: &Value) -> Option<f64> {
+    let mean = time_ms(entry, "mean")?;
+    let stddev = time_ms(entry, "stddev")?;
+    (mean > 0.0).then(|| stddev / mean)
+}
+
+fn metric(
+    metric: &'static str,
+    current: Option<f64>,
+    baseline: Option<f64>,
+    higher_is_better: bool,
+    noise: Option<f64>,
+) -> Option<MetricChange> {
+    let (current, baseline) = (current?, baseline?);
+    if baseline <= 0.0 {
+        return None;
+    }
+    let change = current / baseline - 1.0;
+    Some(MetricChange {
+        metric,
+        change,
+        worse: if higher_is_better {
+            change < 0.0
+        } else {
+            change > 0.0
+        },
+        significant: noise.map(|noise| change.abs() > SIGNIFICANCE_CV_MULTIPLE * noise),
+    })
+}
+
+// 不是标准测试结果（没有 results 数组）时返回 None
+pub fn diff(current: &Value, baseline: &Value) -> Option<ResultDiff> {
+    current.get("results")?.as_array()?;
+    baseline.get("results")?.as_array()?;
+    let current_cases = ok_cases(current);
+    let baseline_cases = ok_cases(baseline);
+    let mut cases: Vec<CaseDiff> = current_cases
+        .iter()
+        .map(|entry| {
+            let id = id_of(entry);
+            let Some(base) = baseline_cases.iter().find(|b| id_of(b) == id) else {
+                return CaseDiff::OnlyCurrent(id.to_string());
+            };
+            let noise = cv(entry).zip(cv(base)).map(|(a, b)| (a * a + b * b).sqrt());
+            let score = |e: &Value| e.get("throughput")?.get("value")?.as_f64();
+            let changes = [
+                metric(
+                    "p50",
+                    time_ms(entry, "p50"),
+                    time_ms(base, "p50"),
+                    false,
+                    noise,
+                ),
+                metric(
+                    "p95",
+                    time_ms(entry, "p95"),
+                    time_ms(base, "p95"),
+                    false,
+                    noise,
+                ),
+                metric("score", score(entry), score(base), true, noise),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            CaseDiff::Compared {
+                id: id.to_string(),
+                changes,
+            }
+        })
+        .collect();
+    cases.extend(
+        baseline_cases
+            .iter()
+            .filter(|b| !current_cases.iter().any(|c| id_of(c) == id_of(b)))
+            .map(|b| CaseDiff::OnlyBaseline(id_of(b).to_string())),
+    );
+    Some(ResultDiff {
+        baseline_started_at: baseline
+            .get("started_at")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        cases,
+    })
+}
+
+// +3.1% / −12.0%，负号用 U+2212 与连字符区分
+pub fn percent(change: f64) -> String {
+    let value = format!("{:.1}%", change.abs() * 100.0);
+    if change < 0.0 {
+        format!("\u{2212}{}", value)
+    } else {
+        format!("+{}", value)
+    }
+}
+
+// 一个用例一行，显著变化标 *
+pub fn case_summary(changes: &[MetricChange]) -> String {
+    changes
+        .iter()
+        .map(|c| {
+            let mark = if c.significant == Some(true) { "*" } else { "" };
+            format!("{} {}{}", c.metric, percent(c.change), mark)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// 导出用的纯文本
+pub fn changelog(diff: &ResultDiff) -> String {
+    let mut out = match &diff.baseline_started_at {
+        Some(at) => format!("对比基准: {}\n", at),
+        None => "对比基准: 时间未知\n".to_string(),
+    };
+    for case in &diff.cases {
+        let line = match case {
+            CaseDiff::Compared { id, changes } => format!("{} {}", id, case_summary(changes)),
+            CaseDiff::OnlyCurrent(id) => format!("{} 基准中没有", id),
+            CaseDiff::OnlyBaseline(id) => format!("{} 本次未完成", id),
+        };
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out.push_str(&format!(
+        "* 变化超过两次运行合并变异系数的 {} 倍，共 {} 项\n",
+        SIGNIFICANCE_CV_MULTIPLE,
+        diff.significant_count()
+    ));
+    out
+}
@@ -0,0 +1,30 @@
+// 分享前对结果 JSON 的变换。匿名化只改写 env 段：系统版本、语言、启动与事件耗时等
+// 可能区分具体设备的字段全部去掉，只保留架构、核心数和系统类别
+use serde_json::{Value, json};
+
+pub fn anonymize(result: &mut Value) {
+    let Some(fields) = result.as_object_mut() else {
+        return;
+    };
+    if let Some(env) = fields.get("env").filter(|env| env.is_object()) {
+        let field = |key: &str| env.get(key).cloned().unwrap_or(Value::Null);
+        let kept = json!({
+            "arch": field("arch"),
+            "cores": field("cores"),
+            "os_family": field("platform"),
+        });
+        fields.insert("env".to_string(), kept);
+    }
+    fields.insert("anonymized".to_string(), Value::Bool(true));
+}
+
+// 解析失败时原样返回，界面上至少还能看到原始结果
+pub fn anonymize_text(text: &str) -> String {
+    match serde_json::from_str::<Value>(text) {
+        Ok(mut result) => {
+            anonymize(&mut result);
+            serde_json::to_string_pretty(&result).unwrap_or_else(|_| text.to_string())
+        }
+        Err(_) => text.to_string(),
+    }
+}
@@ -0,0 +1,736 @@
+// 与界面无关的文本报告：UI、日志、导出共用同一份行内容
+use std::fmt;
+
+use crate::benchmark::{
+    self, AbResult, BATTERY_API, BENCH_SEED, BenchCaseResult, BenchPhase, BenchStepStatus,
+    BenchmarkConfig, BenchmarkResult, CaseOutcome, EntryCheck, OFFLOAD_API, ProgressUpdate,
+    SustainedResult, Verdict, reference_digest,
+};
+use crate::capabilities::{self, Capability};
+use crate::checkpoint::Interrupted;
+use crate::event_probe::{self, ProbeStatus};
+use crate::lifetime::LifetimeStats;
+use crate::report::diff::{self, CaseDiff, ResultDiff};
+use crate::{build_info, env, format, timestamp};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Muted,
+    Success,
+    Warning,
+    Error,
+}
+
+#[derive(Clone)]
+pub struct LineItem {
+    pub label: String,
+    pub value: String,
+    pub severity: Severity,
+}
+
+impl LineItem {
+    pub fn new(label: impl Into<String>, value: impl Into<String>, severity: Severity) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            severity,
+        }
+    }
+
+    pub fn info(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::new(label, value, Severity::Info)
+    }
+}
+
+impl fmt::Display for LineItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.label.is_empty() {
+            write!(f, "{}", self.value)
+        } else {
+            write!(f, "{}: {}", self.label, self.value)
+        }
+    }
+}
+
+pub fn progress_status(update: &ProgressUpdate) -> LineItem {
+    let phase = match update.phase {
+        BenchPhase::Cold => "冷启动",
+        BenchPhase::Warmup => "预热",
+        BenchPhase::Measure => "测试",
+    };
+    let status = match update.status {
+        BenchStepStatus::Started => "开始".to_string(),
+        BenchStepStatus::Running => format!(
+            "块 {}/{} ({})",
+            update.chunk_index,
+            update.chunk_total,
+            format::duration_ms(update.elapsed_ms)
+        ),
+        BenchStepStatus::Finished => {
+            format!("完成 ({})", format::duration_ms(update.elapsed_ms))
+        }
+    };
+    LineItem::info(
+        "",
+        format!(
+            "{} {} {}/{} {}",
+            update.bench_id, phase, update.index, update.total, status
+        ),
+    )
+}
+
+fn outcome_line(case: &BenchCaseResult) -> LineItem {
+    let (label, severity) = match case.outcome {
+        CaseOutcome::Ok => ("完成", Severity::Info),
+        CaseOutcome::Failed => ("失败", Severity::Error),
+        CaseOutcome::Skipped => ("已跳过", Severity::Muted),
+        CaseOutcome::Cancelled => ("已取消", Severity::Warning),
+    };
+    let value = match case.error.as_ref().or(case.skip_reason.as_ref()) {
+        Some(error) => format!("{} ({})", label, error),
+        None => label.to_string(),
+    };
+    LineItem::new(case.id, value, severity)
+}
+
+fn digest_line(case: &BenchCaseResult) -> LineItem {
+    let label = format!("{} digest", case.id);
+    let digest = format!("{:016x}", case.digest);
+    match case.digest_ok {
+        None => LineItem::info(label, digest),
+        Some(true) => LineItem::new(label, format!("{} (校验通过)", digest), Severity::Success),
+        Some(false) => LineItem::new(
+            label,
+            format!(
+                "{} (与参考值 {:016x} 不符)",
+                digest,
+                reference_digest(case.id).unwrap_or_default()
+            ),
+            Severity::Error,
+        ),
+    }
+}
+
+// 单个用例的结果行；运行中每个用例一结束就先展示这几行
+pub fn case_lines(case: &BenchCaseResult) -> Vec<LineItem> {
+    let mut lines = case_detail_lines(case);
+    if let Some(verdict) = &case.verdict {
+        lines.push(verdict_line(case.id, verdict));
+    }
+    lines
+}
+
+fn verdict_line(id: &str, verdict: &Verdict) -> LineItem {
+    let label = format!("{} 验收", id);
+    if verdict.passed {
+        LineItem::new(label, "PASS", Severity::Success)
+    } else {
+        LineItem::new(
+            label,
+            format!("FAIL: {}", verdict.reasons.join("；")),
+            Severity::Error,
+        )
+    }
+}
+
+fn case_detail_lines(case: &BenchCaseResult) -> Vec<LineItem> {
+    if case.outcome != CaseOutcome::Ok {
+        return vec![outcome_line(case)];
+    }
+    let mut lines = vec![digest_line(case)];
+    lines.push(LineItem::info(
+        format!("{} 耗时", case.id),
+        format!(
+            "min {}, p50 {}, p95 {}, max {}",
+            format::duration_ms(case.stats.min),
+            format::duration_ms(case.stats.p50),
+            format::duration_ms(case.stats.p95),
+            format::duration_ms(case.stats.max)
+        ),
+    ));
+    if let Some(cold) = &case.cold {
+        let note = if cold.first_in_process {
+            ""
+        } else {
+            "，非进程内首次执行"
+        };
+        lines.push(LineItem::info(
+            format!("{} 冷启动", case.id),
+            format!(
+                "{} (冷/热 {:.2}x{})",
+                format::duration_ms(cold.time_ms),
+                cold.ratio,
+                note
+            ),
+        ));
+    }
+    if let Some(throughput) = &case.throughput {
+        let kind = if throughput.unit.ends_with("B/s") {
+            "带宽"
+        } else {
+            "吞吐"
+        };
+        lines.push(LineItem::info(
+            format!("{} {}", case.id, kind),
+            format!("{:.1} {}", throughput.value, throughput.unit),
+        ));
+    }
+    if let Some(relative) = &case.relative {
+        let label = format!("{} 相对 {}", case.id, relative.baseline);
+        let ratio = format!("{:.2}x", relative.ratio);
+        lines.push(if relative.flagged == Some(true) {
+            LineItem::new(label, format!("{} (明显变慢)", ratio), Severity::Warning)
+        } else {
+            LineItem::info(label, ratio)
+        });
+    }
+    if !case.retries.is_empty() {
+        let discarded: Vec<String> = case
+            .retries
+            .iter()
+            .map(|retry| {
+                format!(
+                    "#{} {}",
+                    retry.index,
+                    format::duration_ms(retry.discarded_ms)
+                )
+            })
+            .collect();
+        lines.push(LineItem::new(
+            format!("{} 重跑", case.id),
+            format!(
+                "{} 次迭代超过中位数 {}x 已丢弃: {}",
+                case.retries.len(),
+                benchmark::RETRY_FACTOR,
+                discarded.join(", ")
+            ),
+            Severity::Warning,
+        ));
+    }
+    lines
+}
+
+pub fn result_lines(config: &BenchmarkConfig, result: &BenchmarkResult) -> Vec<LineItem> {
+    let mut lines = vec![LineItem::info(
+        "参数",
+        format!(
+            "--seed {} --n1 {} --n2 {} --warmup {} --repeats {} (barrier: {}, policy: {})",
+            BENCH_SEED,
+            config.n1(),
+            config.n2(),
+            config.warmup,
+            config.repeats,
+            config.barrier.as_str(),
+            result.policy.as_str()
+        ),
+    )];
+    if let Some(note) = config.device_class.defaults().note {
+        lines.push(LineItem::new("设备类别", note, Severity::Muted));
+    }
+    if let Some(line) = timer_warning() {
+        lines.push(line);
+    }
+    if result.noise.is_busy() {
+        lines.push(LineItem::new(
+            "环境噪声",
+            format!(
+                "{:.2}，设备较忙，结果波动可能偏大，建议关闭其他应用",
+                result.noise.score
+            ),
+            Severity::Warning,
+        ));
+    }
+    if let Some(after) = &result.conditions_after {
+        let delta = result.conditions_before.compare(after);
+        if delta.significant {
+            lines.push(LineItem::new(
+                "运行条件",
+                format!(
+                    "跑完后空载循环变化 {:+.1}%，可能发生了降频或后台负载变化",
+                    delta.loop_change * 100.0
+                ),
+                Severity::Warning,
+            ));
+        }
+    }
+    for case in &result.cases {
+        lines.extend(case_lines(case));
+    }
+    let verdicts: Vec<&Verdict> = result
+        .cases
+        .iter()
+        .filter_map(|case| case.verdict.as_ref())
+        .collect();
+    if !verdicts.is_empty() {
+        let passed = verdicts.iter().filter(|v| v.passed).count();
+        let severity = if passed == verdicts.len() {
+            Severity::Success
+        } else {
+            Severity::Error
+        };
+        lines.push(LineItem::new(
+            "验收结果",
+            format!("{}/{} 通过", passed, verdicts.len()),
+            severity,
+        ));
+    }
+    if OFFLOAD_API.is_none() {
+        lines.push(LineItem::new(
+            "GPU/宿主卸载",
+            "不可用 (psys_host 未提供计算接口)",
+            Severity::Muted,
+        ));
+    }
+    if BATTERY_API.is_none() {
+        lines.push(LineItem::new(
+            "能耗估计",
+            "不可用 (psys_host 未提供电量接口)",
+            Severity::Muted,
+        ));
+    }
+    lines.push(LineItem::info(
+        "suite_digest",
+        format!("{:016x}", result.suite_digest),
+    ));
+    lines.push(LineItem::new(
+        "final_digest",
+        match result.final_digest {
+            Some(digest) => format!("{:016x} (T1 ^ T2，过渡期保留)", digest),
+            None => "不可比（T1/T2 覆盖了迭代数）".to_string(),
+        },
+        Severity::Muted,
+    ));
+    lines
+}
+
+// 持续/峰值低于该比例时提示降频
+const THROTTLE_WARN_RATIO: f64 = 0.8;
+
+// A/B 任一用例的 p50 变化超过该比例时高亮
+const AB_DELTA_WARN: f64 = 0.05;
+
+pub fn ab_lines(result: &AbResult) -> Vec<LineItem> {
+    let mut lines = vec![LineItem::info(
+        "A/B",
+        format!(
+            "{}: A = {}, B = {} (ABBA 交替 {} 段)",
+            result.variant.as_str(),
+            result.label_a,
+            result.label_b,
+            benchmark::AB_PASSES
+        ),
+    )];
+    if let Some(line) = timer_warning() {
+        lines.push(line);
+    }
+    if result.cancelled {
+        lines.push(LineItem::new("状态", "已取消", Severity::Warning));
+    }
+    for row in &result.rows {
+        let label = format!("{} A/B", row.id);
+        if let Some(error) = &row.error {
+            lines.push(LineItem::new(label, error.as_str(), Severity::Error));
+            continue;
+        }
+        let (Some(a), Some(b), Some(delta)) = (&row.a, &row.b, row.delta_p50()) else {
+            continue;
+        };
+        let value = format!(
+            "A p50 {} · B p50 {} · {:+.1}%",
+            format::duration_ms(a.p50),
+            format::duration_ms(b.p50),
+            delta * 100.0
+        );
+        lines.push(if delta.abs() >= AB_DELTA_WARN {
+            LineItem::new(label, value, Severity::Warning)
+        } else {
+            LineItem::info(label, value)
+        });
+    }
+    lines
+}
+
+pub fn sustained_lines(result: &SustainedResult) -> Vec<LineItem> {
+    let mut lines = vec![LineItem::info(
+        "参数",
+        format!(
+            "持续 {} 分钟，每 {} s 采样一次 ({})",
+            result.minutes,
+            benchmark::SAMPLE_SECS,
+            benchmark::SUSTAINED_KERNEL
+        ),
+    )];
+    if let Some(line) = timer_warning() {
+        lines.push(line);
+    }
+    if result.cancelled {
+        lines.push(LineItem::new(
+            "状态",
+            format!("已取消，共 {} 个采样", result.samples.len()),
+            Severity::Warning,
+        ));
+    }
+    if result.samples.is_empty() {
+        return lines;
+    }
+    let mops = |ops_per_sec: f64| format!("{:.1} Mops/s", ops_per_sec / 1e6);
+    lines.push(LineItem::info("峰值", mops(result.peak_ops_per_sec)));
+    lines.push(LineItem::info("持续", mops(result.sustained_ops_per_sec)));
+    let ratio = format!("{:.2}", result.ratio);
+    lines.push(if result.ratio < THROTTLE_WARN_RATIO {
+        LineItem::new(
+            "持续/峰值",
+            format!("{} (明显降频)", ratio),
+            Severity::Warning,
+        )
+    } else {
+        LineItem::info("持续/峰值", ratio)
+    });
+    lines
+}
+
+pub fn conformance_lines(checks: &[EntryCheck]) -> Vec<LineItem> {
+    let passed = checks.iter().filter(|check| check.passed()).count();
+    let mut lines = vec![LineItem::new(
+        "一致性",
+        format!("{}/{} 通过", passed, checks.len()),
+        if passed == checks.len() {
+            Severity::Success
+        } else {
+            Severity::Error
+        },
+    )];
+    for check in checks {
+        let label = format!("{} seed={} n={}", check.id, check.seed, check.n);
+        lines.push(match &check.actual {
+            Ok(_) if check.passed() => LineItem::new(label, "通过", Severity::Success),
+            Ok(actual) => LineItem::new(
+                label,
+                format!("不符: 期望 {:016x}，实际 {:016x}", check.expected, actual),
+                Severity::Error,
+            ),
+            Err(message) => LineItem::new(label, message.as_str(), Severity::Warning),
+        });
+    }
+    lines
+}
+
+fn timer_warning() -> Option<LineItem> {
+    let timer = benchmark::timer_probe();
+    timer.is_coarse().then(|| {
+        LineItem::new(
+            "计时分辨率",
+            format!(
+                "{} ms，粗于 {} ms，毫秒级统计可能不准确",
+                timer.resolution_ms,
+                benchmark::TimerProbe::COARSE_MS
+            ),
+            Severity::Warning,
+        )
+    })
+}
+
+// 测试页顶部的提示，只在调试构建下显示
+pub fn debug_build_line() -> Option<LineItem> {
+    build_info::is_debug().then(|| {
+        LineItem::new(
+            "调试构建",
+            format!(
+                "opt-level {}，测得的数字没有参考意义，请使用 release 构建",
+                build_info::OPT_LEVEL
+            ),
+            Severity::Warning,
+        )
+    })
+}
+
+// 调试构建标为警告
+pub fn build_line() -> LineItem {
+    let features = build_info::target_features();
+    let value = format!(
+        "{} · opt-level {} · LTO {} · 目标特性 {}",
+        build_info::PROFILE,
+        build_info::OPT_LEVEL,
+        build_info::LTO,
+        if features.is_empty() {
+            "无".to_string()
+        } else {
+            features.join(", ")
+        }
+    );
+    if build_info::is_debug() {
+        LineItem::new(
+            "构建配置",
+            format!("{}（调试构建，数字没有参考意义）", value),
+            Severity::Warning,
+        )
+    } else {
+        LineItem::info("构建配置", value)
+    }
+}
+
+// 「关于」页内容
+pub fn about_lines() -> Vec<LineItem> {
+    let mut lines = vec![LineItem::info("版本", env!("CARGO_PKG_VERSION"))];
+    if let Some(host) = env::host_info() {
+        lines.push(LineItem::info(
+            "平台",
+            format!("{} ({})", host.platform, host.arch),
+        ));
+        lines.push(LineItem::info("系统版本", host.os_version.as_str()));
+        lines.push(LineItem::info("AstroBox 语言", host.language.as_str()));
+    }
+    let timer = benchmark::timer_probe();
+    lines.push(LineItem::new(
+        "计时分辨率",
+        format!(
+            "{:.6} ms · sleep(1 ms) 实测 {} · yield {:.6} ms · now() {:.6} ms",
+            timer.resolution_ms,
+            format::duration_ms(timer.sleep_1ms_ms),
+            timer.yield_ms,
+            timer.now_overhead_ms
+        ),
+        if timer.is_coarse() {
+            Severity::Warning
+        } else {
+            Severity::Info
+        },
+    ));
+    lines.push(build_line());
+    lines.push(match env::startup_ms() {
+        Some(ms) => LineItem::info("插件启动耗时", format::duration_ms(ms)),
+        None => LineItem::new("插件启动耗时", "未记录", Severity::Muted),
+    });
+    lines
+}
+
+// 本地时间后附上偏移，避免与 JSON 里的 UTC 时间混淆
+fn local_time(unix_ms: u64, offset_min: i32) -> String {
+    format!(
+        "{} ({})",
+        timestamp::local(unix_ms, offset_min),
+        timestamp::offset_label(offset_min)
+    )
+}
+
+pub fn started_line(unix_ms: u64, offset_min: i32) -> LineItem {
+    LineItem::info("开始时间", local_time(unix_ms, offset_min))
+}
+
+pub fn lifetime_lines(stats: &LifetimeStats, offset_min: i32) -> Vec<LineItem> {
+    if stats.total_runs == 0 {
+        return vec![LineItem::new("累计统计", "尚无运行记录", Severity::Muted)];
+    }
+    let mut lines = vec![LineItem::info(
+        "累计统计",
+        format!(
+            "共 {} 次运行，总耗时 {}",
+            stats.total_runs,
+            format::duration_ms(stats.total_bench_ms)
+        ),
+    )];
+    let by_profile = stats.runs_by_profile();
+    if !by_profile.is_empty() {
+        let counts: Vec<String> = by_profile
+            .iter()
+            .map(|(profile, count)| format!("{} {} 次", profile, count))
+            .collect();
+        lines.push(LineItem::info("近期按 profile", counts.join(" · ")));
+    }
+    if let (Some(first), Some(last)) = (stats.first_run_unix_ms, stats.last_run_unix_ms) {
+        lines.push(LineItem::info("首次运行", local_time(first, offset_min)));
+        lines.push(LineItem::info("最近运行", local_time(last, offset_min)));
+    }
+    if let Some(baseline) = &stats.baseline_conditions {
+        lines.push(LineItem::info(
+            "条件基准",
+            format!(
+                "空载循环 p50 {:.1} µs · 噪声 {:.3}",
+                baseline.loop_p50_us, baseline.noise_score
+            ),
+        ));
+    }
+    for case in stats.cases.iter().filter(|case| !case.entries.is_empty()) {
+        let changed = case.changed_count();
+        let mut value = format!(
+            "最佳 {} · 最差 {} · 中位 {} ({} 次)",
+            format::duration_ms(case.best()),
+            format::duration_ms(case.worst()),
+            format::duration_ms(case.median()),
+            case.entries.len()
+        );
+        if changed > 0 {
+            value.push_str(&format!("，其中 {} 次条件与基准不同", changed));
+        }
+        lines.push(LineItem::new(
+            format!("{} 历史 p50", case.id),
+            value,
+            if changed > 0 {
+                Severity::Warning
+            } else {
+                Severity::Info
+            },
+        ));
+    }
+    lines
+}
+
+// 对比视图：显著变差为警告，显著变好为成功，都在噪声以内时淡化
+pub fn diff_lines(result: &ResultDiff) -> Vec<LineItem> {
+    let mut lines = vec![LineItem::info(
+        "对比基准",
+        format!(
+            "{} · 显著变化 {} 项（* 超出合并变异系数 {} 倍）",
+            result.baseline_started_at.as_deref().unwrap_or("时间未知"),
+            result.significant_count(),
+            diff::SIGNIFICANCE_CV_MULTIPLE
+        ),
+    )];
+    lines.extend(case_diff_lines(result));
+    lines
+}
+
+// 对比页里勾选的历史结果，label 为该份结果的设备与时间
+pub fn history_diff_lines(label: &str, result: &ResultDiff) -> Vec<LineItem> {
+    let mut lines = vec![LineItem::info(
+        "对比",
+        format!("{} · 显著变化 {} 项", label, result.significant_count()),
+    )];
+    lines.extend(case_diff_lines(result));
+    lines
+}
+
+fn case_diff_lines(result: &ResultDiff) -> Vec<LineItem> {
+    let mut lines = Vec::with_capacity(result.cases.len());
+    for case in &result.cases {
+        lines.push(match case {
+            CaseDiff::Compared { id, changes } => {
+                let significant = |worse: bool| {
+                    changes
+                        .iter()
+                        .any(|c| c.significant == Some(true) && c.worse == worse)
+                };
+                let severity = if significant(true) {
+                    Severity::Warning
+                } else if significant(false) {
+                    Severity::Success
+                } else if changes.iter().all(|c| c.significant == Some(false)) {
+                    Severity::Muted
+                } else {
+                    Severity::Info
+                };
+                LineItem::new(id.as_str(), diff::case_summary(changes), severity)
+            }
+            CaseDiff::OnlyCurrent(id) => LineItem::new(id.as_str(), "基准中没有", Severity::Muted),
+            CaseDiff::OnlyBaseline(id) => LineItem::new(id.as_str(), "本次未完成", Severity::Muted),
+        });
+    }
+    lines
+}
+
+// 上次运行被宿主中途结束时留下的记录
+pub fn interrupted_lines(record: &Interrupted, offset_min: i32) -> Vec<LineItem> {
+    let position = if record.case.is_empty() {
+        "第一次正式测量前".to_string()
+    } else if record.index < record.total {
+        format!("{} 第 {} 次测量", record.case, record.index + 1)
+    } else {
+        format!("{} 测完之后", record.case)
+    };
+    let mut lines = vec![
+        LineItem::new(
+            "上次运行",
+            format!("上次运行在 {} 中断 ({})", position, record.profile),
+            Severity::Warning,
+        ),
+        started_line(record.started_unix_ms, offset_min),
+    ];
+    if let Some((min, p50, max)) = record.partial_stats() {
+        lines.push(LineItem::info(
+            format!("{} 部分统计", record.case),
+            format!(
+                "{}/{} 次 · min {} · p50 {} · max {}",
+                record.times_ms.len(),
+                record.total,
+                format::duration_ms(min),
+                format::duration_ms(p50),
+                format::duration_ms(max)
+            ),
+        ));
+    }
+    for (id, p50) in &record.completed {
+        lines.push(LineItem::new(
+            id.as_str(),
+            format!("p50 {}（中断前已完成）", format::duration_ms(*p50)),
+            Severity::Muted,
+        ));
+    }
+    lines
+}
+
+fn capability_line(label: &str, capability: &Capability) -> LineItem {
+    match capability {
+        Capability::Available(detail) => LineItem::new(label, detail.as_str(), Severity::Success),
+        Capability::Missing(detail) => LineItem::new(label, detail.as_str(), Severity::Muted),
+        Capability::Failed(detail) => LineItem::new(label, detail.as_str(), Severity::Warning),
+    }
+}
+
+// 「关于」页的能力清单，附构建信息，方便贴进问题反馈
+pub fn capability_lines() -> Vec<LineItem> {
+    let inventory = capabilities::inventory();
+    let ids: Vec<&str> = benchmark::CASES.iter().map(|case| case.id).collect();
+    let custom: Vec<&str> = benchmark::all_cases()[ids.len()..]
+        .iter()
+        .map(|case| case.id)
+        .collect();
+    let mut lines = vec![
+        LineItem::info(
+            "套件版本",
+            format!(
+                "{} · 夹具 v{}",
+                env!("CARGO_PKG_VERSION"),
+                benchmark::FIXTURE_VERSION
+            ),
+        ),
+        LineItem::info("内置用例", format!("{} 个: {}", ids.len(), ids.join(", "))),
+    ];
+    if !custom.is_empty() {
+        lines.push(LineItem::info(
+            "外部用例",
+            format!("{} 个: {}", custom.len(), custom.join(", ")),
+        ));
+    }
+    lines.extend([
+        LineItem::info("构建目标", build_info::TARGET),
+        capability_line("界面", &inventory.ui),
+        capability_line("存储", &inventory.storage),
+        capability_line("网络", &inventory.network),
+        capability_line("事件", &capabilities::events()),
+        capability_line("进度卡片", &capabilities::status_card()),
+        capability_line("设备信息", &inventory.device),
+        capability_line("系统信息", &inventory.os),
+    ]);
+    lines
+}
+
+pub fn event_probe_line() -> Option<LineItem> {
+    let label = "事件往返";
+    match event_probe::status() {
+        ProbeStatus::Idle => None,
+        ProbeStatus::Waiting(received) => Some(LineItem::new(
+            label,
+            format!(
+                "等待宿主回传 ({}/{})，宿主不回投插件消息时会一直停在这里",
+                received,
+                event_probe::PINGS
+            ),
+            Severity::Muted,
+        )),
+        ProbeStatus::Done(ms) => Some(LineItem::info(
+            label,
+            format!("中位数 {}", format::duration_ms(ms)),
+        )),
+    }
+}
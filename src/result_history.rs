@@ -0,0 +1,144 @@
+// 最近的测试结果：每次跑完（各 profile，含队列中的）把完整结果 JSON 追加到数据目录，对比页从中挑选若干份导出成合集，
+// 从其他设备导入的合集也合并进来一起对比。只保留最近 MAX_RESULTS 份，经临时文件改名替换整体写入
+use std::sync::Mutex;
+
+use serde_json::Value;
+
+use crate::storage;
+
+const HISTORY_FILE: &str = "history/results.json";
+pub const MAX_RESULTS: usize = 20;
+
+// 同一进程内的读-改-写串行执行
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn text_field<'a>(value: &'a Value, pointer: &str) -> &'a str {
+    value.pointer(pointer).and_then(Value::as_str).unwrap_or("")
+}
+
+// 匿名化的结果没有 env.platform，只有取自它的 os_family，两者当作同一个字段
+fn os(result: &Value) -> &str {
+    match text_field(result, "/env/platform") {
+        "" => text_field(result, "/env/os_family"),
+        platform => platform,
+    }
+}
+
+// 同一次运行无论导入几次、是否匿名都只保留一份：按开始时间与设备区分
+pub fn key(result: &Value) -> String {
+    format!(
+        "{}|{}|{}",
+        text_field(result, "/started_at"),
+        os(result),
+        text_field(result, "/env/arch")
+    )
+}
+
+// 列表与对比里的标题，如「quick · android aarch64 · 2026-10-14T08:00:00.000Z（导入）」，
+// 没有 profile 字段的旧结果为 standard
+pub fn label(result: &Value) -> String {
+    let device = [os(result), text_field(result, "/env/arch")]
+        .iter()
+        .filter(|field| !field.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let device = if device.is_empty() {
+        "未知设备"
+    } else {
+        &device
+    };
+    let started = match text_field(result, "/started_at") {
+        "" => "时间未知",
+        started => started,
+    };
+    let profile = match text_field(result, "/profile") {
+        "" => "standard",
+        profile => profile,
+    };
+    let imported = result.get("imported").and_then(Value::as_bool) == Some(true);
+    format!(
+        "{} · {} · {}{}",
+        profile,
+        device,
+        started,
+        if imported { "（导入）" } else { "" }
+    )
+}
+
+// 文件不存在或无法解析时为空
+pub fn load() -> Vec<Value> {
+    let Ok(bytes) = storage::read(HISTORY_FILE) else {
+        return Vec::new();
+    };
+    match serde_json::from_slice::<Value>(&bytes) {
+        Ok(Value::Array(entries)) => entries,
+        _ => {
+            tracing::warn!("ignoring unreadable {}", HISTORY_FILE);
+            Vec::new()
+        }
+    }
+}
+
+fn save(entries: &[Value]) -> Result<(), String> {
+    let bytes = serde_json::to_vec(entries).map_err(|err| err.to_string())?;
+    storage::write_atomic(HISTORY_FILE, &bytes).map_err(|err| err.to_string())
+}
+
+// 追加若干份结果，已有的（key 相同）跳过，超出上限时丢掉最旧的。返回新增份数与更新后的全部条目
+pub fn merge(results: Vec<Value>) -> Result<(usize, Vec<Value>), String> {
+    let _guard = LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut entries = load();
+    let mut added = 0;
+    for result in results {
+        if entries.iter().any(|entry| key(entry) == key(&result)) {
+            continue;
+        }
+        entries.push(result);
+        added += 1;
+    }
+    if entries.len() > MAX_RESULTS {
+        entries.drain(..entries.len() - MAX_RESULTS);
+    }
+    save(&entries)?;
+    Ok((added, entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::{key, label};
+    use crate::report::bundle;
+
+    fn result(started_at: &str) -> Value {
+        json!({
+            "started_at": started_at,
+            "env": { "platform": "android", "arch": "aarch64", "cores": 8, "model": "X1" },
+            "results": [],
+        })
+    }
+
+    #[test]
+    fn anonymized_bundle_keeps_distinct_keys() {
+        let first = result("2026-10-14T08:00:00.000Z");
+        let second = result("2026-10-14T09:00:00.000Z");
+        let imported = bundle::import(&bundle::export(&[&first, &second], true)).unwrap();
+        assert_eq!(imported.len(), 2);
+        assert!(imported[0].pointer("/env/platform").is_none());
+        assert_ne!(key(&imported[0]), key(&imported[1]));
+        // 与未匿名的同一次运行视为同一份
+        assert_eq!(key(&imported[0]), key(&first));
+        assert_eq!(key(&imported[1]), key(&second));
+    }
+
+    #[test]
+    fn anonymized_label_falls_back_to_os_family() {
+        let original = result("2026-10-14T08:00:00.000Z");
+        let imported = bundle::import(&bundle::export(&[&original], true)).unwrap();
+        assert_eq!(
+            label(&imported[0]),
+            "standard · android aarch64 · 2026-10-14T08:00:00.000Z（导入）"
+        );
+    }
+}
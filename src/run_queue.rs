@@ -0,0 +1,84 @@
+// 运行队列：排入几次不同 profile 的运行，依次执行，两次之间冷却一段时间让设备回落到接近空载的温度。
+// 开跑后上移与移除都不可用，正在跑的队列只能暂停或取消。
+// 队列只保存在内存里，插件重新加载后清空
+use std::sync::Mutex;
+
+use crate::benchmark::{BenchmarkConfig, Profile};
+
+pub const PROFILES: [&str; 4] = ["standard", "quick", "sustained", "ab"];
+pub const MAX_QUEUE: usize = 8;
+pub const COOLDOWN_SECS: u64 = 30;
+
+// quick 是标准测试的缩短版：不改动保存的设置，只作用于这一次运行
+const QUICK_WARMUP: usize = 1;
+const QUICK_REPEATS: usize = 3;
+
+static QUEUE: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+fn queue() -> std::sync::MutexGuard<'static, Vec<&'static str>> {
+    QUEUE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// 返回 PROFILES 里的同名项，未知名字时报错
+pub fn profile(name: &str) -> Result<&'static str, String> {
+    PROFILES
+        .iter()
+        .copied()
+        .find(|profile| *profile == name)
+        .ok_or_else(|| format!("未知的 profile: {}，可选 {}", name, PROFILES.join(" / ")))
+}
+
+pub fn apply(profile: &str, config: &mut BenchmarkConfig) {
+    match profile {
+        "quick" => {
+            config.profile = Profile::Standard;
+            config.warmup = QUICK_WARMUP;
+            config.repeats = QUICK_REPEATS;
+        }
+        "sustained" => config.profile = Profile::Sustained,
+        "ab" => config.profile = Profile::Ab,
+        _ => config.profile = Profile::Standard,
+    }
+}
+
+// 返回排入后的队列长度
+pub fn enqueue(name: &str) -> Result<usize, String> {
+    let profile = profile(name)?;
+    let mut queue = queue();
+    if queue.len() >= MAX_QUEUE {
+        return Err(format!("队列最多 {} 项", MAX_QUEUE));
+    }
+    queue.push(profile);
+    Ok(queue.len())
+}
+
+pub fn remove(index: usize) -> bool {
+    let mut queue = queue();
+    if index >= queue.len() {
+        return false;
+    }
+    queue.remove(index);
+    true
+}
+
+// 与前一项交换；已在队首时不动
+pub fn move_up(index: usize) -> bool {
+    let mut queue = queue();
+    if index == 0 || index >= queue.len() {
+        return false;
+    }
+    queue.swap(index - 1, index);
+    true
+}
+
+pub fn entries() -> Vec<&'static str> {
+    queue().clone()
+}
+
+// 取出队首，执行时逐项出队，中途取消后剩下的仍留在队列里
+pub fn pop_front() -> Option<&'static str> {
+    let mut queue = queue();
+    (!queue.is_empty()).then(|| queue.remove(0))
+}
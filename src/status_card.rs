@@ -0,0 +1,88 @@
+// 进度卡片：插件加载时向宿主登记一张文字卡片，测试期间写入「Benchmark 43%」式的简短进度，
+// 离开插件界面后仍能在宿主的卡片区看到。百分比变化时才重写，宿主重绘卡片时补发最近一次的内容
+use std::sync::{Mutex, OnceLock};
+
+use crate::astrobox::psys_host::{register, ui};
+use crate::benchmark::ProgressUpdate;
+
+pub const CARD_ID: &str = "benchmark_card";
+const CARD_NAME: &str = "跑分进度";
+const IDLE_TEXT: &str = "Benchmark 未在运行";
+
+struct Card {
+    text: String,
+    // 上一次写入的百分比，None 表示当前不在运行
+    percent: Option<usize>,
+}
+
+static CARD: Mutex<Card> = Mutex::new(Card {
+    text: String::new(),
+    percent: None,
+});
+// 登记结果，失败时之后的写入都跳过
+static REGISTERED: OnceLock<Result<(), String>> = OnceLock::new();
+
+fn card() -> std::sync::MutexGuard<'static, Card> {
+    CARD.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn show(text: &str) {
+    if matches!(REGISTERED.get(), Some(Ok(()))) {
+        ui::render_to_text_card(CARD_ID, text);
+    }
+}
+
+// 在 on_load 里调用一次
+pub fn register() {
+    let outcome = wit_bindgen::block_on(async {
+        register::register_card(register::CardType::Text, CARD_ID, CARD_NAME).await
+    })
+    .map_err(|()| "宿主拒绝登记卡片".to_string());
+    if let Err(err) = &outcome {
+        tracing::warn!("failed to register {}: {}", CARD_ID, err);
+    }
+    let _ = REGISTERED.set(outcome);
+    set(IDLE_TEXT);
+}
+
+// None 表示还没登记（不在插件环境中）
+pub fn registration() -> Option<&'static Result<(), String>> {
+    REGISTERED.get()
+}
+
+fn set(text: &str) {
+    card().text = text.to_string();
+    show(text);
+}
+
+pub fn on_progress(update: &ProgressUpdate) {
+    if update.total_steps == 0 {
+        return;
+    }
+    let percent = (update.completed_steps * 100 / update.total_steps).min(100);
+    let text = {
+        let mut card = card();
+        if card.percent == Some(percent) {
+            return;
+        }
+        card.percent = Some(percent);
+        card.text = format!("Benchmark {}%", percent);
+        card.text.clone()
+    };
+    show(&text);
+}
+
+// 运行结束时写入最终状态，如「测试完成」
+pub fn finish(status: &str) {
+    card().percent = None;
+    set(&format!("Benchmark {}", status));
+}
+
+// 宿主请求重绘卡片时调用
+pub fn render(card_id: &str) {
+    if card_id != CARD_ID {
+        return;
+    }
+    let text = card().text.clone();
+    show(if text.is_empty() { IDLE_TEXT } else { &text });
+}
@@ -0,0 +1,55 @@
+// 插件数据目录下的文件读写。psys_host 没有提供存储接口，
+// 这里与日志一样直接使用 WASI 预开放目录下的相对路径
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+pub const DATA_DIR: &str = "data";
+
+pub fn path(name: &str) -> PathBuf {
+    Path::new(DATA_DIR).join(name)
+}
+
+pub fn write(name: &str, bytes: &[u8]) -> io::Result<()> {
+    let path = path(name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, bytes)
+}
+
+pub fn read(name: &str) -> io::Result<Vec<u8>> {
+    fs::read(path(name))
+}
+
+// 先写临时文件再改名替换，写到一半崩溃时原文件保持完整
+pub fn write_atomic(name: &str, bytes: &[u8]) -> io::Result<()> {
+    let path = path(name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, bytes)?;
+    fs::rename(tmp, path)
+}
+
+// 追加到文件末尾，文件不存在时创建
+pub fn append(name: &str, bytes: &[u8]) -> io::Result<()> {
+    let path = path(name);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(bytes)
+}
+
+// 文件不存在时视为已删除
+pub fn remove(name: &str) -> io::Result<()> {
+    match fs::remove_file(path(name)) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
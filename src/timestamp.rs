@@ -0,0 +1,79 @@
+// 运行时间戳：JSON 一律写 UTC ISO-8601，界面按用户选择的 UTC 偏移显示本地时间。
+// WASI 只提供 UTC 墙上时钟而没有时区数据库，偏移量取自宿主语言区域的推测或设置
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// 设置里可选的 UTC 偏移（分钟）
+pub const OFFSET_CHOICES: [i32; 10] = [-480, -300, 0, 60, 120, 180, 330, 420, 480, 540];
+
+pub fn now_unix_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+// 1970-01-01 起的天数换算为公历年月日（Howard Hinnant 的 civil_from_days）
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+// (年, 月, 日, 时, 分, 秒)
+fn split(unix_secs: i64) -> (i64, u32, u32, u32, u32, u32) {
+    let (year, month, day) = civil_from_days(unix_secs.div_euclid(86_400));
+    let secs = unix_secs.rem_euclid(86_400) as u32;
+    (year, month, day, secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+// 1_700_000_000_000 -> "2023-11-14T22:13:20Z"
+pub fn iso8601_utc(unix_ms: u64) -> String {
+    let (y, mo, d, h, mi, s) = split((unix_ms / 1000) as i64);
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, mo, d, h, mi, s)
+}
+
+// 界面用的本地时间，如 "2023-11-15 06:13:20"
+pub fn local(unix_ms: u64, offset_min: i32) -> String {
+    let (y, mo, d, h, mi, s) = split((unix_ms / 1000) as i64 + i64::from(offset_min) * 60);
+    format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", y, mo, d, h, mi, s)
+}
+
+// 480 -> "UTC+8"，330 -> "UTC+5:30"，0 -> "UTC"
+pub fn offset_label(offset_min: i32) -> String {
+    if offset_min == 0 {
+        return "UTC".to_string();
+    }
+    let sign = if offset_min < 0 { '-' } else { '+' };
+    let abs = offset_min.unsigned_abs();
+    if abs.is_multiple_of(60) {
+        format!("UTC{}{}", sign, abs / 60)
+    } else {
+        format!("UTC{}{}:{:02}", sign, abs / 60, abs % 60)
+    }
+}
+
+// 按语言区域标签的地区部分推测偏移，认不出时用 UTC；多时区国家取人口最多的时区
+pub fn guess_offset(locale: &str) -> i32 {
+    let region = locale
+        .split(['-', '_', '.'])
+        .skip(1)
+        .find(|part| part.len() == 2)
+        .map(|part| part.to_ascii_uppercase());
+    match region.as_deref() {
+        Some("CN" | "HK" | "MO" | "TW" | "SG" | "MY" | "PH") => 480,
+        Some("JP" | "KR") => 540,
+        Some("TH" | "VN" | "ID") => 420,
+        Some("IN") => 330,
+        Some("RU" | "TR") => 180,
+        Some("UA" | "GR" | "FI") => 120,
+        Some("DE" | "FR" | "ES" | "IT" | "NL" | "PL" | "SE") => 60,
+        Some("US" | "CA") => -300,
+        _ => 0,
+    }
+}
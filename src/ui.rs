@@ -1,56 +1,301 @@
-use crate::astrobox::psys_host::{self, ui};
-use crate::benchmark::{self, BenchPhase, BenchStepStatus, ProgressUpdate};
-use std::sync::{Mutex, OnceLock};
+use crate::astrobox::psys_host::{self, dialog, ui};
+use crate::benchmark::{
+    self, AbVariant, Barrier, BenchCaseResult, BenchPhase, BenchStepStatus, BenchmarkConfig,
+    CaseThreshold, FailurePolicy, PlannedCase, Profile, ProgressMeter, ProgressSplit,
+    ProgressUpdate, SuiteOutcome, TimeUnit,
+};
+use crate::checkpoint;
+use crate::env;
+use crate::event_probe;
+use crate::format::{self, Locale};
+use crate::heartbeat;
+use crate::host::PluginHost;
+use crate::lifetime;
+use crate::progress_stream;
+use crate::report::text::{self, LineItem, Severity};
+use crate::report::{bundle, diff, share};
+use crate::result_history;
+use crate::run_queue;
+use crate::status_card;
+use crate::storage;
+use crate::timestamp;
+use crate::wearable;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 pub const START_BENCH_EVENT: &str = "benchmark_start";
+pub const TOGGLE_BARRIER_EVENT: &str = "toggle_barrier";
+pub const TOGGLE_VERIFY_EVENT: &str = "toggle_verify";
+pub const TOGGLE_ABORT_EVENT: &str = "toggle_abort_on_mismatch";
+pub const TOGGLE_POLICY_EVENT: &str = "toggle_failure_policy";
+pub const CANCEL_BENCH_EVENT: &str = "benchmark_cancel";
+pub const PAUSE_BENCH_EVENT: &str = "benchmark_pause";
+pub const TOGGLE_COLD_EVENT: &str = "toggle_cold_start";
+pub const CYCLE_CHUNKS_EVENT: &str = "cycle_chunks";
+pub const TOGGLE_STREAM_EVENT: &str = "toggle_progress_stream";
+pub const TOGGLE_TIMER_CORRECTION_EVENT: &str = "toggle_timer_correction";
+pub const TOGGLE_RETRY_EVENT: &str = "toggle_retry_noisy";
+pub const TOGGLE_TRACE_EVENT: &str = "toggle_export_trace";
+pub const TOGGLE_BATCH_EVENT: &str = "toggle_batch_chunks";
+pub const TOGGLE_TIME_UNIT_EVENT: &str = "toggle_time_unit";
+pub const CYCLE_TIME_DECIMALS_EVENT: &str = "cycle_time_decimals";
+pub const TOGGLE_ANONYMIZE_EVENT: &str = "toggle_anonymize";
+pub const TOGGLE_ACCESSIBLE_EVENT: &str = "toggle_accessible";
+pub const TOGGLE_PROFILE_EVENT: &str = "toggle_profile";
+pub const CYCLE_AB_VARIANT_EVENT: &str = "cycle_ab_variant";
+pub const CYCLE_UTC_OFFSET_EVENT: &str = "cycle_utc_offset";
+pub const CYCLE_MINUTES_EVENT: &str = "cycle_sustained_minutes";
+pub const TOGGLE_HEARTBEAT_LOG_EVENT: &str = "toggle_heartbeat_log";
+pub const GENERATE_FIXTURE_EVENT: &str = "conformance_generate";
+pub const CHECK_FIXTURE_EVENT: &str = "conformance_check";
+pub const SEND_SUMMARY_EVENT: &str = "send_summary_to_wearable";
+pub const SET_BASELINE_EVENT: &str = "compare_set_baseline";
+pub const IMPORT_BASELINE_EVENT: &str = "compare_import_baseline";
+// 历史结果：事件 id 为前缀加列表下标的是勾选，勾选的结果与本次结果对比、导出为合集
+pub const HISTORY_SELECT_EVENT_PREFIX: &str = "history_select:";
+pub const EXPORT_BUNDLE_EVENT: &str = "compare_export_bundle";
+pub const IMPORT_BUNDLE_EVENT: &str = "compare_import_bundle";
+// 用例说明的展开开关，事件 id 为前缀加用例 id
+pub const CASE_INFO_EVENT_PREFIX: &str = "case_info:";
+pub const CASE_SELECT_EVENT_PREFIX: &str = "case_select:";
+pub const PROBE_EVENTS_EVENT: &str = "probe_event_latency";
+pub const SHOW_BENCH_TAB_EVENT: &str = "tab_bench";
+pub const SHOW_ABOUT_TAB_EVENT: &str = "tab_about";
+// 运行队列：加入当前 profile 或 quick，事件 id 为前缀加队列下标的是上移与移除
+pub const QUEUE_ADD_EVENT: &str = "queue_add";
+pub const QUEUE_ADD_QUICK_EVENT: &str = "queue_add_quick";
+pub const QUEUE_RUN_EVENT: &str = "queue_run";
+pub const QUEUE_UP_EVENT_PREFIX: &str = "queue_up:";
+pub const QUEUE_REMOVE_EVENT_PREFIX: &str = "queue_remove:";
+
+// 阈值输入框的 CHANGE 事件，后缀为用例 id，payload 为输入内容
+pub const THRESHOLD_P50_EVENT_PREFIX: &str = "threshold_p50:";
+pub const THRESHOLD_SCORE_EVENT_PREFIX: &str = "threshold_score:";
+// 迭代数覆盖输入框，同样以用例 id 为后缀
+pub const N_OVERRIDE_EVENT_PREFIX: &str = "n_override:";
+// 对外广播状态变化的宿主事件名，payload 为 {"state": "..."}。
+// 开始请求因已有测试在运行被拒绝时也经它回一条 {"state": "busy", "current": "..."}
+pub const RUN_STATE_EVENT: &str = "benchmark_state";
+
+// 用户偏好存放在插件数据目录，切换时立即写入
+const PREFS_FILE: &str = "prefs.json";
+// 本机生成的一致性基准，供其他语言移植版比对
+const FIXTURE_FILE: &str = "conformance/rust.json";
+const PROGRESS_BAR_WIDTH: u32 = 280;
+const PROGRESS_BAR_HEIGHT: u32 = 8;
+// 实时吞吐迷你图保留最近多少个点，每点一根竖条，按窗口内最大值归一
+const SPARKLINE_LEN: usize = 40;
+const SPARKLINE_HEIGHT: u32 = 24;
+const SPARKLINE_BAR_WIDTH: u32 = 5;
+// 无障碍显示下的字号倍数
+const LARGE_TEXT_SCALE: f32 = 1.5;
+
+// 对比视图的基准结果，可由本机结果设定或从文件导入
+const BASELINE_FILE: &str = "compare/baseline.json";
+// 逐块耗时的 Chrome trace，可用 chrome://tracing 或 Perfetto 打开
+const TRACE_DIR: &str = "traces";
+// 导出的结果合集与对比基准放在同一目录
+const BUNDLE_DIR: &str = "compare";
+
+// 测试进行中宿主仍会投递点击事件，暂停与取消都在下一次迭代前生效
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RunState {
+    Idle,
+    Preparing,
+    Warmup,
+    Measuring,
+    // 测量结束、正在汇总结果
+    Cooldown,
+    Paused,
+    Cancelling,
+    Finished,
+    Failed,
+}
+
+impl RunState {
+    fn as_str(self) -> &'static str {
+        match self {
+            RunState::Idle => "idle",
+            RunState::Preparing => "preparing",
+            RunState::Warmup => "warmup",
+            RunState::Measuring => "measuring",
+            RunState::Cooldown => "cooldown",
+            RunState::Paused => "paused",
+            RunState::Cancelling => "cancelling",
+            RunState::Finished => "finished",
+            RunState::Failed => "failed",
+        }
+    }
+
+    fn is_running(self) -> bool {
+        match self {
+            RunState::Preparing
+            | RunState::Warmup
+            | RunState::Measuring
+            | RunState::Cooldown
+            | RunState::Paused
+            | RunState::Cancelling => true,
+            RunState::Idle | RunState::Finished | RunState::Failed => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tab {
+    Bench,
+    About,
+}
+
+// 结果展示数据只在用例结束、整套跑完或基准变化时改动。状态与快照共享同一份，
+// 逐块的进度更新只复制指针；修改时用 Arc::make_mut，没有快照在用时原地改写
+#[derive(Clone, Default)]
+struct ResultView {
+    lines: Vec<LineItem>,
+    json: Option<String>,
+    // 发往手表的结果摘要，测试完成后才有
+    summary: Option<String>,
+    // 当前结果与基准的对比，结果或基准变化时重算
+    compare_lines: Vec<LineItem>,
+}
 
 struct UiState {
     root_element_id: Option<String>,
-    running: bool,
+    prefs_restored: bool,
+    tab: Tab,
+    // 展示与发送的结果 JSON 去掉设备标识
+    anonymize: bool,
+    // 高对比配色与大字号
+    accessible: bool,
+    locale: Locale,
+    // 界面显示本地时间用的 UTC 偏移（分钟）
+    utc_offset_min: i32,
+    // 只在设置变化时改动，与结果一样经 Arc 共享
+    config: Arc<BenchmarkConfig>,
+    run_state: RunState,
     progress_done: usize,
     progress_total: usize,
+    // 按耗时加权的进度，快照里只带算好的比例
+    meter: ProgressMeter,
     status: String,
-    result_lines: Vec<String>,
-    result_json: Option<String>,
+    live_rate: Option<f64>,
+    // 正式测量中逐块的滚动吞吐，换用例时清空，不同用例的吞吐不可比
+    sparkline: Arc<VecDeque<f64>>,
+    sparkline_case: &'static str,
+    // 暂停前的预热或测量状态，继续时回到它
+    resume_state: RunState,
+    error: Option<String>,
+    results: Arc<ResultView>,
+    baseline_json: Option<String>,
+    // 最近的测试结果（各 profile，含导入的），与 result_history 的文件同步
+    history: Vec<serde_json::Value>,
+    // 勾选的历史结果，按 result_history::key 记录，不随偏好保存
+    history_selected: Vec<String>,
+    // 界面列表用的标题与勾选状态，history 或勾选变化时经 refresh_history_rows 重建
+    history_rows: Arc<Vec<(String, bool)>>,
+    expanded_cases: Vec<&'static str>,
 }
 
 #[derive(Clone)]
 struct UiSnapshot {
-    running: bool,
+    tab: Tab,
+    anonymize: bool,
+    accessible: bool,
+    locale: Locale,
+    utc_offset_min: i32,
+    config: Arc<BenchmarkConfig>,
+    run_state: RunState,
     progress_done: usize,
     progress_total: usize,
+    progress_split: ProgressSplit,
     status: String,
-    result_lines: Vec<String>,
-    result_json: Option<String>,
+    live_rate: Option<f64>,
+    sparkline: Arc<VecDeque<f64>>,
+    error: Option<String>,
+    results: Arc<ResultView>,
+    expanded_cases: Vec<&'static str>,
+    queue: Vec<&'static str>,
+    // 历史结果的标题与是否勾选
+    history: Arc<Vec<(String, bool)>>,
+}
+
+impl UiSnapshot {
+    fn theme(&self) -> Theme {
+        Theme {
+            accessible: self.accessible,
+        }
+    }
 }
 
 static UI_STATE: OnceLock<Mutex<UiState>> = OnceLock::new();
 
 fn ui_state() -> &'static Mutex<UiState> {
     UI_STATE.get_or_init(|| {
+        let config = BenchmarkConfig::for_class(env::device_class());
         Mutex::new(UiState {
             root_element_id: None,
-            running: false,
+            prefs_restored: false,
+            tab: Tab::Bench,
+            anonymize: false,
+            accessible: false,
+            locale: Locale::Zh,
+            utc_offset_min: env::guessed_utc_offset(),
+            progress_total: benchmark::total_steps(&config),
+            config: Arc::new(config),
+            run_state: RunState::Idle,
             progress_done: 0,
-            progress_total: benchmark::TOTAL_STEPS,
+            meter: ProgressMeter::default(),
             status: "等待开始".to_string(),
-            result_lines: Vec::new(),
-            result_json: None,
+            live_rate: None,
+            sparkline: Arc::new(VecDeque::with_capacity(SPARKLINE_LEN)),
+            sparkline_case: "",
+            resume_state: RunState::Idle,
+            error: None,
+            results: Arc::default(),
+            baseline_json: None,
+            history: Vec::new(),
+            history_selected: Vec::new(),
+            history_rows: Arc::default(),
+            expanded_cases: Vec::new(),
         })
     })
 }
 
 fn snapshot_from(state: &UiState) -> UiSnapshot {
     UiSnapshot {
-        running: state.running,
+        tab: state.tab,
+        anonymize: state.anonymize,
+        accessible: state.accessible,
+        locale: state.locale,
+        utc_offset_min: state.utc_offset_min,
+        config: Arc::clone(&state.config),
+        run_state: state.run_state,
         progress_done: state.progress_done,
         progress_total: state.progress_total,
+        progress_split: state.meter.split(),
         status: state.status.clone(),
-        result_lines: state.result_lines.clone(),
-        result_json: state.result_json.clone(),
+        live_rate: state.live_rate,
+        sparkline: Arc::clone(&state.sparkline),
+        error: state.error.clone(),
+        results: Arc::clone(&state.results),
+        expanded_cases: state.expanded_cases.clone(),
+        queue: run_queue::entries(),
+        history: Arc::clone(&state.history_rows),
     }
 }
 
+fn refresh_history_rows(state: &mut UiState) {
+    let rows = state
+        .history
+        .iter()
+        .map(|entry| {
+            let selected = state.history_selected.contains(&result_history::key(entry));
+            (result_history::label(entry), selected)
+        })
+        .collect();
+    state.history_rows = Arc::new(rows);
+}
+
 fn update_state_and_render<F>(update: F)
 where
     F: FnOnce(&mut UiState),
@@ -70,155 +315,1957 @@ where
     }
 }
 
-fn format_progress_status(update: &ProgressUpdate) -> String {
-    let phase = match update.phase {
-        BenchPhase::Warmup => "预热",
-        BenchPhase::Measure => "测试",
+impl Tab {
+    fn as_str(self) -> &'static str {
+        match self {
+            Tab::Bench => "bench",
+            Tab::About => "about",
+        }
+    }
+}
+
+// 语言跟随宿主设置，不在这里保存
+fn save_prefs(state: &UiState) {
+    let config = &state.config;
+    let prefs = serde_json::json!({
+        "tab": state.tab.as_str(),
+        "anonymize": state.anonymize,
+        "accessible": state.accessible,
+        "utc_offset_min": state.utc_offset_min,
+        "barrier": config.barrier.as_str(),
+        "verify_digests": config.verify_digests,
+        "abort_on_mismatch": config.abort_on_mismatch,
+        "failure_policy": config.failure_policy.as_str(),
+        "cold_start": config.cold_start,
+        "chunks": config.chunks,
+        "stream_progress": config.stream_progress,
+        "profile": config.profile.as_str(),
+        "sustained_minutes": config.sustained_minutes,
+        "heartbeat_log": config.heartbeat_log,
+        "timer_correction": config.timer_correction,
+        "retry_noisy": config.retry_noisy,
+        "export_trace": config.export_trace,
+        "batch_chunks": config.batch_chunks,
+        "time_unit": config.time_unit.as_str(),
+        "time_decimals": config.time_decimals,
+        "ab_variant": config.ab_variant.as_str(),
+        "thresholds": config
+            .thresholds
+            .iter()
+            .map(|t| {
+                let limits = serde_json::json!({
+                    "max_p50_ms": t.max_p50_ms,
+                    "min_score": t.min_score,
+                });
+                (t.id.to_string(), limits)
+            })
+            .collect::<serde_json::Map<_, _>>(),
+        "n_overrides": config
+            .n_overrides
+            .iter()
+            .map(|&(id, n)| (id.to_string(), serde_json::json!(n)))
+            .collect::<serde_json::Map<_, _>>(),
+        // 记录未选中的用例而不是选中的，之后新增的用例默认参与
+        "skip_cases": config.skip_cases,
+        "expanded_cases": state.expanded_cases,
+    });
+    if let Err(err) = storage::write(PREFS_FILE, prefs.to_string().as_bytes()) {
+        tracing::warn!("failed to save prefs: {}", err);
+    }
+}
+
+// 缺失或无法识别的字段保持默认值
+fn restore_prefs(state: &mut UiState) {
+    let Ok(bytes) = storage::read(PREFS_FILE) else {
+        return;
     };
-    let status = match update.status {
-        BenchStepStatus::Started => "开始",
-        BenchStepStatus::Finished => "完成",
+    let prefs: serde_json::Value = match serde_json::from_slice(&bytes) {
+        Ok(prefs) => prefs,
+        Err(err) => {
+            tracing::warn!("ignoring invalid prefs: {}", err);
+            return;
+        }
     };
-    format!(
-        "{} {} {}/{} {}",
-        update.bench_id, phase, update.index, update.total, status
-    )
+    let text = |key: &str| prefs.get(key).and_then(|v| v.as_str());
+    let flag = |key: &str| prefs.get(key).and_then(|v| v.as_bool());
+    if let Some(value) = flag("anonymize") {
+        state.anonymize = value;
+    }
+    if let Some(value) = flag("accessible") {
+        state.accessible = value;
+    }
+    let offset = prefs.get("utc_offset_min").and_then(|v| v.as_i64());
+    if let Some(offset) = offset.map(|o| o as i32)
+        && timestamp::OFFSET_CHOICES.contains(&offset)
+    {
+        state.utc_offset_min = offset;
+    }
+    let known_cases = |key: &str| -> Option<Vec<&'static str>> {
+        let saved = prefs.get(key)?.as_array()?;
+        Some(
+            benchmark::all_cases()
+                .into_iter()
+                .map(|case| case.id)
+                .filter(|id| saved.iter().any(|v| v.as_str() == Some(id)))
+                .collect(),
+        )
+    };
+    if let Some(expanded) = known_cases("expanded_cases") {
+        state.expanded_cases = expanded;
+    }
+    let config = Arc::make_mut(&mut state.config);
+    if let Some(skipped) = known_cases("skip_cases") {
+        config.skip_cases = skipped;
+    }
+
+    match text("tab") {
+        Some("bench") => state.tab = Tab::Bench,
+        Some("about") => state.tab = Tab::About,
+        _ => {}
+    }
+    match text("barrier") {
+        Some("black_box") => config.barrier = Barrier::BlackBox,
+        Some("volatile") => config.barrier = Barrier::Volatile,
+        _ => {}
+    }
+    match text("profile") {
+        Some("standard") => config.profile = Profile::Standard,
+        Some("sustained") => config.profile = Profile::Sustained,
+        Some("ab") => config.profile = Profile::Ab,
+        _ => {}
+    }
+    match text("ab_variant") {
+        Some("barrier") => config.ab_variant = AbVariant::Barrier,
+        Some("chunks") => config.ab_variant = AbVariant::Chunks,
+        Some("timer_correction") => config.ab_variant = AbVariant::TimerCorrection,
+        _ => {}
+    }
+    match text("time_unit") {
+        Some("ms") => config.time_unit = TimeUnit::Ms,
+        Some("us") => config.time_unit = TimeUnit::Us,
+        _ => {}
+    }
+    match text("failure_policy") {
+        Some("continue_others") => config.failure_policy = FailurePolicy::ContinueOthers,
+        Some("abort_suite") => config.failure_policy = FailurePolicy::AbortSuite,
+        _ => {}
+    }
+    if let Some(value) = flag("verify_digests") {
+        config.verify_digests = value;
+    }
+    if let Some(value) = flag("abort_on_mismatch") {
+        config.abort_on_mismatch = value;
+    }
+    if let Some(value) = flag("cold_start") {
+        config.cold_start = value;
+    }
+    if let Some(value) = flag("stream_progress") {
+        config.stream_progress = value;
+    }
+    if let Some(value) = flag("timer_correction") {
+        config.timer_correction = value;
+    }
+    if let Some(value) = flag("retry_noisy") {
+        config.retry_noisy = value;
+    }
+    if let Some(value) = flag("heartbeat_log") {
+        config.heartbeat_log = value;
+    }
+    if let Some(value) = flag("export_trace") {
+        config.export_trace = value;
+    }
+    if let Some(value) = flag("batch_chunks") {
+        config.batch_chunks = value;
+    }
+    if let Some(saved) = prefs.get("thresholds") {
+        let limit = |id: &str, key: &str| saved.get(id).and_then(|t| t.get(key)?.as_f64());
+        config.thresholds = benchmark::CASES
+            .iter()
+            .map(|case| CaseThreshold {
+                id: case.id,
+                max_p50_ms: limit(case.id, "max_p50_ms"),
+                min_score: limit(case.id, "min_score"),
+            })
+            .filter(CaseThreshold::is_set)
+            .collect();
+    }
+    // 只恢复内置用例的覆盖，超出范围的值丢弃
+    if let Some(saved) = prefs.get("n_overrides") {
+        config.n_overrides = benchmark::CASES
+            .iter()
+            .filter_map(|case| {
+                let n = saved.get(case.id)?.as_u64()?;
+                let (min, max) = benchmark::n_override_range(case);
+                (min..=max).contains(&n).then_some((case.id, n))
+            })
+            .collect();
+    }
+    let minutes = prefs.get("sustained_minutes").and_then(|v| v.as_u64());
+    if let Some(minutes) = minutes.map(|m| m as u32)
+        && benchmark::MINUTES_CHOICES.contains(&minutes)
+    {
+        config.sustained_minutes = minutes;
+    }
+    // 只接受界面可选的分块数
+    let chunks = prefs.get("chunks").and_then(|v| v.as_u64());
+    if let Some(chunks) = chunks.map(|c| c as usize)
+        && benchmark::CHUNK_CHOICES.contains(&chunks)
+    {
+        config.chunks = chunks;
+    }
+    let decimals = prefs.get("time_decimals").and_then(|v| v.as_u64());
+    if let Some(decimals) = decimals.map(|d| d as u32)
+        && benchmark::TIME_DECIMALS_CHOICES.contains(&decimals)
+    {
+        config.time_decimals = decimals;
+    }
+    // 用例选择与轮数都影响总步数，进度条要按恢复后的设置计算
+    state.progress_total = benchmark::total_steps(&state.config);
 }
 
-fn build_result_lines(result: &benchmark::BenchmarkResult) -> Vec<String> {
-    vec![
-        format!(
-            "参数: --seed {} --n1 {} --n2 {} --warmup {} --repeats {}",
-            benchmark::BENCH_SEED,
-            benchmark::BENCH_N1,
-            benchmark::BENCH_N2,
-            benchmark::BENCH_WARMUP,
-            benchmark::BENCH_REPEATS
-        ),
-        format!("{} digest: {:016x}", result.t1.id, result.t1.digest),
-        format!(
-            "{} ms: min {:.3}, p50 {:.3}, p95 {:.3}, max {:.3}",
-            result.t1.id,
-            result.t1.stats.min,
-            result.t1.stats.p50,
-            result.t1.stats.p95,
-            result.t1.stats.max
-        ),
-        format!("{} digest: {:016x}", result.t2.id, result.t2.digest),
-        format!(
-            "{} ms: min {:.3}, p50 {:.3}, p95 {:.3}, max {:.3}",
-            result.t2.id,
-            result.t2.stats.min,
-            result.t2.stats.p50,
-            result.t2.stats.p95,
-            result.t2.stats.max
-        ),
-        format!("final_digest: {:016x}", result.final_digest),
-    ]
+fn transition(state: &mut UiState, next: RunState) {
+    if state.run_state == next {
+        return;
+    }
+    state.run_state = next;
+    let payload = serde_json::json!({ "state": next.as_str() }).to_string();
+    psys_host::event::send_event(RUN_STATE_EVENT, &payload);
+}
+
+// 回应被拒绝的开始请求，状态本身不变
+fn reply_busy() {
+    let current = ui_state()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .run_state;
+    tracing::warn!("start rejected: already {}", current.as_str());
+    let payload = serde_json::json!({ "state": "busy", "current": current.as_str() }).to_string();
+    psys_host::event::send_event(RUN_STATE_EVENT, &payload);
+}
+
+fn live_rate(update: &ProgressUpdate) -> Option<f64> {
+    match update.phase {
+        BenchPhase::Measure => update.ops_per_sec,
+        BenchPhase::Cold | BenchPhase::Warmup => None,
+    }
+}
+
+fn push_sparkline(state: &mut UiState, update: &ProgressUpdate) {
+    let Some(rate) = live_rate(update).filter(|_| update.chunk_index > 0) else {
+        return;
+    };
+    let sparkline = Arc::make_mut(&mut state.sparkline);
+    if state.sparkline_case != update.bench_id {
+        sparkline.clear();
+        state.sparkline_case = update.bench_id;
+    }
+    if sparkline.len() == SPARKLINE_LEN {
+        sparkline.pop_front();
+    }
+    sparkline.push_back(rate);
+}
+
+// 计划内的跳过仍算正常完成，只在状态里注明
+fn finish_state(outcome: &SuiteOutcome) -> (RunState, &'static str) {
+    match (outcome.failures.is_empty(), outcome.halted) {
+        (true, _) if outcome.skipped > 0 => (RunState::Finished, "测试完成（部分用例已跳过）"),
+        (true, _) => (RunState::Finished, "测试完成"),
+        (false, true) => (RunState::Failed, "测试已中止"),
+        (false, false) => (RunState::Failed, "测试完成（部分用例失败）"),
+    }
 }
 
 fn run_benchmark_with_ui() {
-    let (root, snapshot) = {
+    run_with(None, |_| {});
+}
+
+// adjust 只作用于这一次运行，不改动界面上的设置；已有测试在跑时返回 false。
+// tag 是记入累计统计的 profile 名，None 时取调整后配置的 profile
+pub fn run_with<F>(tag: Option<&'static str>, adjust: F) -> bool
+where
+    F: FnOnce(&mut BenchmarkConfig),
+{
+    // 占住闸门直到本函数返回，其间到达的开始请求都会被拒绝
+    let Some(_slot) = benchmark::try_start() else {
+        reply_busy();
+        return false;
+    };
+    let (root, snapshot, config, tag) = {
         let mut state = ui_state()
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
-        if state.running {
-            return;
-        }
-        state.running = true;
+        transition(&mut state, RunState::Preparing);
         state.progress_done = 0;
-        state.progress_total = benchmark::TOTAL_STEPS;
         state.status = "准备测试...".to_string();
-        state.result_lines.clear();
-        state.result_json = None;
+        state.live_rate = None;
+        state.sparkline = Arc::default();
+        state.sparkline_case = "";
+        state.error = None;
+        state.results = Arc::default();
         let root = state.root_element_id.clone();
+        let mut config = BenchmarkConfig {
+            render_target: root.clone(),
+            ..(*state.config).clone()
+        };
+        adjust(&mut config);
+        let tag = tag.unwrap_or(config.profile.as_str());
+        state.progress_total = benchmark::total_steps(&config);
+        state.meter = ProgressMeter::new(planned_with_history(&config));
         let snapshot = snapshot_from(&state);
-        (root, snapshot)
+        (root, snapshot, config, tag)
     };
 
     if let Some(root) = root {
         psys_host::ui::render(&root, build_main_ui(&snapshot));
     }
 
-    let result = benchmark::run_benchmark(|update| {
-        let status = format_progress_status(&update);
+    progress_stream::reset();
+    checkpoint::begin(timestamp::now_unix_ms(), tag);
+    let stress = config.profile == Profile::Sustained;
+    if stress {
+        heartbeat::begin(config.sustained_minutes, config.heartbeat_log);
+    }
+    let mut on_progress = |update: ProgressUpdate| {
+        checkpoint::on_progress(&update);
+        status_card::on_progress(&update);
+        if stress {
+            heartbeat::on_progress(&update);
+        }
+        if config.stream_progress {
+            progress_stream::emit(&update);
+        }
+        let status = text::progress_status(&update).to_string();
         update_state_and_render(|state| {
+            if !matches!(state.run_state, RunState::Cancelling | RunState::Paused) {
+                let next = match update.phase {
+                    BenchPhase::Cold | BenchPhase::Warmup => RunState::Warmup,
+                    BenchPhase::Measure => RunState::Measuring,
+                };
+                transition(state, next);
+            }
             state.status = status;
+            state.live_rate = live_rate(&update);
+            if matches!(update.status, BenchStepStatus::Running) {
+                push_sparkline(state, &update);
+            }
             state.progress_done = update.completed_steps;
             state.progress_total = update.total_steps;
+            state.meter.observe(&update);
         });
-    });
+    };
+
+    // 用例一结束就先追加它的结果行，整套跑完后再换成含参数与对照比值的完整报告
+    let on_case = |case: &BenchCaseResult| {
+        checkpoint::on_case(case);
+        let lines = text::case_lines(case);
+        update_state_and_render(|state| Arc::make_mut(&mut state.results).lines.extend(lines));
+    };
+
+    let started = Instant::now();
+    let offset = snapshot.utc_offset_min;
+    let (outcome, result_lines, json, summary) = match config.profile {
+        Profile::Standard => {
+            let result = benchmark::run_benchmark(&config, &PluginHost, &mut on_progress, on_case);
+            update_state_and_render(|state| transition(state, RunState::Cooldown));
+            let outcome = benchmark::suite_outcome(&result.cases);
+            record_lifetime(
+                started,
+                result.started_unix_ms,
+                tag,
+                &result.cases,
+                Some(&result.conditions_before),
+            );
+            let mut result_lines = vec![text::started_line(result.started_unix_ms, offset)];
+            result_lines.extend(text::result_lines(&config, &result));
+            if config.export_trace {
+                result_lines.push(save_trace(&result.trace));
+            }
+            let summary = wearable::summary_json(&result);
+            // quick 与 standard 都经 run_benchmark，profile 名在这里补上
+            let json = with_profile(&result.json, tag);
+            (outcome, result_lines, json, Some(summary))
+        }
+        Profile::Sustained => {
+            let result = benchmark::run_sustained(&config, &PluginHost, &mut on_progress);
+            update_state_and_render(|state| transition(state, RunState::Cooldown));
+            heartbeat::finish(result.cancelled);
+            let outcome = SuiteOutcome {
+                failures: if result.cancelled {
+                    vec![format!("{} 持续测试已取消", benchmark::SUSTAINED_KERNEL)]
+                } else {
+                    Vec::new()
+                },
+                halted: result.cancelled,
+                skipped: 0,
+            };
+            record_lifetime(started, result.started_unix_ms, tag, &[], None);
+            let mut result_lines = vec![text::started_line(result.started_unix_ms, offset)];
+            result_lines.extend(text::sustained_lines(&result));
+            (outcome, result_lines, result.json, None)
+        }
+        Profile::Ab => {
+            let result =
+                benchmark::run_ab(&config, config.ab_variant, &PluginHost, &mut on_progress);
+            update_state_and_render(|state| transition(state, RunState::Cooldown));
+            let outcome = SuiteOutcome {
+                failures: result
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.error.as_ref().map(|e| format!("{} {}", row.id, e)))
+                    .collect(),
+                halted: result.cancelled,
+                skipped: 0,
+            };
+            record_lifetime(started, result.started_unix_ms, tag, &[], None);
+            let mut result_lines = vec![text::started_line(result.started_unix_ms, offset)];
+            result_lines.extend(text::ab_lines(&result));
+            (outcome, result_lines, result.json, None)
+        }
+    };
 
-    let result_lines = build_result_lines(&result);
+    checkpoint::finish();
+    if !benchmark::cancel_requested() {
+        remember_result(&json);
+    }
+    let (next, status) = finish_state(&outcome);
+    let failures = outcome.failures;
+    status_card::finish(status);
+    for failure in &failures {
+        tracing::error!("benchmark case failed: {}", failure);
+    }
+
+    for line in &result_lines {
+        tracing::info!("{}", line);
+    }
     update_state_and_render(|state| {
-        state.running = false;
+        transition(state, next);
         state.progress_done = state.progress_total;
-        state.status = "测试完成".to_string();
-        state.result_lines = result_lines;
-        state.result_json = Some(result.json);
+        state.meter.complete();
+        state.status = status.to_string();
+        state.live_rate = None;
+        state.sparkline = Arc::default();
+        state.error = (!failures.is_empty()).then(|| failures.join("\n"));
+        state.results = Arc::new(ResultView {
+            lines: result_lines,
+            json: Some(json),
+            summary,
+            compare_lines: Vec::new(),
+        });
+        refresh_compare(state);
     });
+    true
 }
 
-pub fn ui_event_processor(evtype: ui::Event, event: &str) {
-    match evtype {
-        ui::Event::Click => match event {
-            START_BENCH_EVENT => run_benchmark_with_ui(),
-            _ => {}
-        },
+// 依次执行队列，逐项出队；某次运行被取消时停下，剩下的留在队列里。返回执行的次数
+pub fn run_queue() -> Result<usize, String> {
+    if benchmark::run_active() {
+        reply_busy();
+        return Err("busy: 已有测试在运行".to_string());
+    }
+    let mut ran = 0;
+    while let Some(profile) = run_queue::pop_front() {
+        if ran > 0 {
+            queue_cooldown();
+        }
+        tracing::info!(
+            "queued run {} ({}), {} left",
+            ran + 1,
+            profile,
+            run_queue::entries().len()
+        );
+        if !run_with(Some(profile), |config| run_queue::apply(profile, config)) {
+            return Err("busy: 已有测试在运行".to_string());
+        }
+        ran += 1;
+        if benchmark::cancel_requested() {
+            tracing::info!("queue stopped after a cancelled run");
+            break;
+        }
+    }
+    if ran == 0 {
+        return Err("队列为空".to_string());
+    }
+    Ok(ran)
+}
+
+// 两次排队运行之间的冷却，每秒刷新一次倒计时
+fn queue_cooldown() {
+    for left in (1..=run_queue::COOLDOWN_SECS).rev() {
+        update_state_and_render(|state| {
+            state.status = format!("队列冷却中，{} 秒后开始下一项", left);
+        });
+        std::thread::sleep(Duration::from_secs(1));
+    }
+}
+
+fn enqueue(profile: &str) {
+    if let Err(err) = run_queue::enqueue(profile) {
+        tracing::warn!("enqueue {} failed: {}", profile, err);
+    }
+    refresh();
+}
+
+fn start_queue() {
+    if let Err(err) = run_queue() {
+        tracing::warn!("run queue failed: {}", err);
+    }
+}
+
+fn queue_action(event: &str) {
+    let index = |suffix: &str| suffix.parse::<usize>().ok();
+    if let Some(i) = event.strip_prefix(QUEUE_UP_EVENT_PREFIX).and_then(index) {
+        run_queue::move_up(i);
+    } else if let Some(i) = event
+        .strip_prefix(QUEUE_REMOVE_EVENT_PREFIX)
+        .and_then(index)
+    {
+        run_queue::remove(i);
+    } else {
+        return;
+    }
+    refresh();
+}
+
+// 供宿主命令查询的运行状态
+pub fn status_json() -> serde_json::Value {
+    let state = ui_state()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    serde_json::json!({
+        "state": state.run_state.as_str(),
+        "status": state.status,
+        "profile": state.config.profile.as_str(),
+        "progress_done": state.progress_done,
+        "progress_total": state.progress_total,
+        "has_result": state.results.json.is_some(),
+    })
+}
+
+// 最近一次结果 JSON，按界面上的匿名化开关处理，与界面展示的内容一致
+pub fn shared_result_json() -> Option<String> {
+    let state = ui_state()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let json = state.results.json.as_ref()?;
+    Some(if state.anonymize {
+        share::anonymize_text(json)
+    } else {
+        json.clone()
+    })
+}
+
+pub fn summary() -> Option<String> {
+    ui_state()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .results
+        .summary
+        .clone()
+}
+
+// 用历史 p50 作为各用例的预计单步耗时，首次运行没有历史时由进度估计自行折算
+fn planned_with_history(config: &BenchmarkConfig) -> Vec<PlannedCase> {
+    let history = lifetime::load();
+    let mut plan = benchmark::step_plan(config);
+    for case in &mut plan {
+        if case.hint_ms.is_none() {
+            case.hint_ms = history
+                .cases
+                .iter()
+                .find(|h| h.id == case.id && !h.entries.is_empty())
+                .map(|h| h.median());
+        }
+    }
+    plan
+}
+
+// 持续与 A/B 模式只计入次数和耗时
+fn record_lifetime(
+    started: Instant,
+    started_unix_ms: u64,
+    profile: &str,
+    cases: &[BenchCaseResult],
+    conditions: Option<&benchmark::Conditions>,
+) {
+    let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+    if let Err(err) = lifetime::record(started_unix_ms, elapsed_ms, profile, cases, conditions) {
+        tracing::warn!("failed to update lifetime stats: {}", err);
+    }
+}
+
+fn with_profile(json: &str, profile: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(json) {
+        Ok(mut value) => {
+            value["profile"] = serde_json::Value::from(profile);
+            serde_json::to_string_pretty(&value).unwrap_or_else(|_| json.to_string())
+        }
+        Err(_) => json.to_string(),
+    }
+}
+
+// 跑完的结果（各 profile，含队列中的）存入历史，供对比页挑选
+fn remember_result(json: &str) {
+    let Ok(result) = serde_json::from_str(json) else {
+        return;
+    };
+    match result_history::merge(vec![result]) {
+        Ok((_, entries)) => {
+            let mut state = ui_state()
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            state.history = entries;
+            refresh_history_rows(&mut state);
+        }
+        Err(err) => tracing::warn!("failed to save result history: {}", err),
+    }
+}
+
+// 每次运行一个文件，按秒级时间戳命名，避免覆盖之前的 trace
+fn save_trace(spans: &[benchmark::ChunkSpan]) -> LineItem {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let name = format!("{}/trace-{}.json", TRACE_DIR, secs);
+    match storage::write(&name, benchmark::chrome_trace(spans).as_bytes()) {
+        Ok(()) => LineItem::info(
+            "Trace",
+            format!(
+                "{} 个块已导出到 {}",
+                spans.len(),
+                storage::path(&name).display()
+            ),
+        ),
+        Err(err) => LineItem::new("Trace", format!("导出失败: {}", err), Severity::Warning),
+    }
+}
+
+fn toggle_barrier() {
+    update_config(|config| config.barrier = config.barrier.toggled());
+}
+
+fn update_config<F>(update: F)
+where
+    F: FnOnce(&mut BenchmarkConfig),
+{
+    update_state_and_render(|state| {
+        if !state.run_state.is_running() {
+            update(Arc::make_mut(&mut state.config));
+            state.progress_total = benchmark::total_steps(&state.config);
+            save_prefs(state);
+        }
+    });
+}
+
+fn toggle_policy() {
+    update_config(|config| {
+        config.failure_policy = match config.failure_policy {
+            FailurePolicy::ContinueOthers => FailurePolicy::AbortSuite,
+            FailurePolicy::AbortSuite => FailurePolicy::ContinueOthers,
+        };
+    });
+}
+
+fn toggle_profile() {
+    update_config(|config| {
+        config.profile = match config.profile {
+            Profile::Standard => Profile::Sustained,
+            Profile::Sustained => Profile::Ab,
+            Profile::Ab => Profile::Standard,
+        };
+    });
+}
+
+fn cycle_utc_offset() {
+    update_state_and_render(|state| {
+        let choices = timestamp::OFFSET_CHOICES;
+        let next = choices
+            .iter()
+            .position(|&offset| offset == state.utc_offset_min)
+            .map_or(0, |i| (i + 1) % choices.len());
+        state.utc_offset_min = choices[next];
+        save_prefs(state);
+    });
+}
+
+fn cycle_minutes() {
+    update_config(|config| {
+        let choices = benchmark::MINUTES_CHOICES;
+        let next = choices
+            .iter()
+            .position(|&minutes| minutes == config.sustained_minutes)
+            .map_or(0, |i| (i + 1) % choices.len());
+        config.sustained_minutes = choices[next];
+    });
+}
+
+fn cycle_time_decimals() {
+    update_config(|config| {
+        let choices = benchmark::TIME_DECIMALS_CHOICES;
+        let next = choices
+            .iter()
+            .position(|&decimals| decimals == config.time_decimals)
+            .map_or(0, |i| (i + 1) % choices.len());
+        config.time_decimals = choices[next];
+    });
+}
+
+fn cycle_chunks() {
+    update_config(|config| {
+        let choices = benchmark::CHUNK_CHOICES;
+        let next = choices
+            .iter()
+            .position(|&chunks| chunks == config.chunks)
+            .map_or(0, |i| (i + 1) % choices.len());
+        config.chunks = choices[next];
+    });
+}
+
+// 取消在下一次迭代前生效，暂停中的运行会立即结束等待
+fn cancel_benchmark() {
+    update_state_and_render(|state| {
+        if state.run_state.is_running() {
+            benchmark::request_cancel();
+            transition(state, RunState::Cancelling);
+            state.status = "正在取消...".to_string();
+        }
+    });
+}
+
+fn toggle_pause() {
+    update_state_and_render(|state| match state.run_state {
+        RunState::Warmup | RunState::Measuring => {
+            benchmark::request_pause();
+            state.resume_state = state.run_state;
+            transition(state, RunState::Paused);
+            state.status = "已暂停".to_string();
+        }
+        RunState::Paused => {
+            benchmark::resume();
+            let next = state.resume_state;
+            transition(state, next);
+            state.status = "继续测试...".to_string();
+        }
         _ => {}
+    });
+}
+
+// 由宿主的文件选择器读入一个 JSON 文件：其他移植版的一致性基准或对比基准
+fn pick_json_file() -> Result<String, String> {
+    let config = dialog::PickConfig {
+        read: true,
+        copy_to: None,
+    };
+    let filter = dialog::FilterConfig {
+        multiple: false,
+        extensions: vec!["json".to_string()],
+        default_directory: String::new(),
+        default_file_name: String::new(),
+    };
+    let picked = wit_bindgen::block_on(async { dialog::pick_file(&config, &filter).await });
+    if picked.data.is_empty() {
+        return Err("未选择文件".to_string());
+    }
+    String::from_utf8(picked.data).map_err(|_| format!("{} 不是 UTF-8 文本", picked.name))
+}
+
+// 当前结果与基准都是标准测试结果时才有对比；勾选的历史结果逐份接在基准之后，跳过当前结果本身
+fn refresh_compare(state: &mut UiState) {
+    let mut lines = compare(
+        state.results.json.as_deref(),
+        state.baseline_json.as_deref(),
+    )
+    .map(|result| text::diff_lines(&result))
+    .unwrap_or_default();
+    let current = state
+        .results
+        .json
+        .as_deref()
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok());
+    if let Some(current) = current {
+        let current_key = result_history::key(&current);
+        for entry in &state.history {
+            let key = result_history::key(entry);
+            if key == current_key || !state.history_selected.contains(&key) {
+                continue;
+            }
+            if let Some(result) = diff::diff(&current, entry) {
+                let label = result_history::label(entry);
+                lines.extend(text::history_diff_lines(&label, &result));
+            }
+        }
+    }
+    Arc::make_mut(&mut state.results).compare_lines = lines;
+}
+
+fn toggle_history(index: usize) {
+    update_state_and_render(|state| {
+        let Some(key) = state.history.get(index).map(result_history::key) else {
+            return;
+        };
+        match state.history_selected.iter().position(|k| *k == key) {
+            Some(position) => {
+                state.history_selected.remove(position);
+            }
+            None => state.history_selected.push(key),
+        }
+        refresh_history_rows(state);
+        refresh_compare(state);
+    });
+}
+
+// 把历史结果打包成合集。indices 为 None 时取界面上勾选的，按界面上的匿名化开关处理
+pub fn bundle(indices: Option<&[usize]>) -> Result<String, String> {
+    let state = ui_state()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let entries: Vec<&serde_json::Value> = match indices {
+        Some(indices) => indices
+            .iter()
+            .map(|&index| {
+                state
+                    .history
+                    .get(index)
+                    .ok_or_else(|| format!("历史里没有第 {} 项", index))
+            })
+            .collect::<Result<_, _>>()?,
+        None => state
+            .history
+            .iter()
+            .filter(|entry| state.history_selected.contains(&result_history::key(entry)))
+            .collect(),
+    };
+    if entries.is_empty() {
+        return Err("没有选中任何历史结果".to_string());
+    }
+    Ok(bundle::export(&entries, state.anonymize))
+}
+
+// 合并一份合集，导入的结果自动勾选以便对比。返回合集里的份数与新增份数
+pub fn import_bundle(text: &str) -> Result<(usize, usize), String> {
+    let results = bundle::import(text)?;
+    let count = results.len();
+    let keys: Vec<String> = results.iter().map(result_history::key).collect();
+    let (added, entries) = result_history::merge(results)?;
+    update_state_and_render(|state| {
+        state.history = entries;
+        for key in keys {
+            if !state.history_selected.contains(&key) {
+                state.history_selected.push(key);
+            }
+        }
+        refresh_history_rows(state);
+        refresh_compare(state);
+    });
+    Ok((count, added))
+}
+
+fn export_bundle() {
+    let outcome = bundle(None).and_then(|content| {
+        let secs = timestamp::now_unix_ms() / 1000;
+        let name = format!("{}/bundle-{}.json", BUNDLE_DIR, secs);
+        storage::write(&name, content.as_bytes())
+            .map(|()| storage::path(&name))
+            .map_err(|err| err.to_string())
+    });
+    update_state_and_render(|state| {
+        state.status = match outcome {
+            Ok(path) => format!("结果合集已导出到 {}", path.display()),
+            Err(err) => format!("导出结果合集: {}", err),
+        };
+    });
+}
+
+fn import_bundle_file() {
+    let outcome = pick_json_file().and_then(|text| import_bundle(&text));
+    update_state_and_render(|state| {
+        state.status = match outcome {
+            Ok((count, added)) => format!("已导入 {} 份结果，新增 {} 份", count, added),
+            Err(err) => format!("导入结果合集: {}", err),
+        };
+    });
+}
+
+fn compare(current: Option<&str>, baseline: Option<&str>) -> Option<diff::ResultDiff> {
+    let current = serde_json::from_str(current?).ok()?;
+    let baseline = serde_json::from_str(baseline?).ok()?;
+    diff::diff(&current, &baseline)
+}
+
+fn store_baseline(state: &mut UiState, json: String) {
+    if let Err(err) = storage::write(BASELINE_FILE, json.as_bytes()) {
+        tracing::warn!("failed to save baseline: {}", err);
+    }
+    state.baseline_json = Some(json);
+    refresh_compare(state);
+}
+
+fn set_baseline() {
+    update_state_and_render(|state| {
+        if let Some(json) = state.results.json.clone() {
+            store_baseline(state, json);
+            state.status = "已将本次结果设为对比基准".to_string();
+        }
+    });
+}
+
+fn import_baseline() {
+    let picked = pick_json_file().and_then(|text| {
+        let value: serde_json::Value =
+            serde_json::from_str(&text).map_err(|err| format!("不是有效的 JSON: {}", err))?;
+        if value.get("results").and_then(|r| r.as_array()).is_none() {
+            return Err("不是标准测试结果".to_string());
+        }
+        Ok(text)
+    });
+    update_state_and_render(|state| match picked {
+        Ok(json) => {
+            store_baseline(state, json);
+            state.status = "已导入对比基准".to_string();
+        }
+        Err(err) => state.status = format!("导入对比基准: {}", err),
+    });
+}
+
+// 供宿主命令使用：当前结果与基准的变更摘要
+pub fn changelog(baseline: Option<&str>) -> Result<String, String> {
+    let (current, stored) = {
+        let state = ui_state()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        (state.results.json.clone(), state.baseline_json.clone())
+    };
+    let current = current.ok_or_else(|| "还没有测试结果".to_string())?;
+    let baseline = baseline
+        .map(str::to_string)
+        .or(stored)
+        .ok_or_else(|| "没有对比基准".to_string())?;
+    compare(Some(&current), Some(&baseline))
+        .map(|result| diff::changelog(&result))
+        .ok_or_else(|| "两份结果都需要是标准测试结果".to_string())
+}
+
+fn run_conformance(check: bool) {
+    let fixture = if check {
+        match pick_json_file() {
+            Ok(fixture) => Some(fixture),
+            Err(err) => {
+                update_state_and_render(|state| state.status = format!("一致性比对: {}", err));
+                return;
+            }
+        }
+    } else {
+        None
+    };
+    let Some(_slot) = benchmark::try_start() else {
+        reply_busy();
+        return;
+    };
+    let (root, snapshot) = {
+        let mut state = ui_state()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        transition(&mut state, RunState::Measuring);
+        let status = if check {
+            "正在比对一致性基准..."
+        } else {
+            "正在生成一致性基准..."
+        };
+        state.status = status.to_string();
+        state.error = None;
+        state.results = Arc::default();
+        (state.root_element_id.clone(), snapshot_from(&state))
+    };
+    if let Some(root) = root {
+        psys_host::ui::render(&root, build_main_ui(&snapshot));
+    }
+
+    let outcome = match fixture {
+        Some(fixture) => benchmark::check_fixture(&fixture).map(|checks| {
+            let passed = checks.iter().all(|check| check.passed());
+            (text::conformance_lines(&checks), None, passed)
+        }),
+        None => {
+            let fixture = benchmark::generate_fixture();
+            let saved = match storage::write(FIXTURE_FILE, fixture.as_bytes()) {
+                Ok(()) => {
+                    let path = storage::path(FIXTURE_FILE);
+                    LineItem::info("一致性基准", format!("已保存到 {}", path.display()))
+                }
+                Err(err) => LineItem::new(
+                    "一致性基准",
+                    format!("保存失败: {}", err),
+                    Severity::Warning,
+                ),
+            };
+            Ok((vec![saved], Some(fixture), true))
+        }
+    };
+
+    update_state_and_render(|state| match outcome {
+        Ok((lines, json, true)) => {
+            transition(state, RunState::Finished);
+            state.status = "一致性检查完成".to_string();
+            state.results = Arc::new(ResultView {
+                lines,
+                json,
+                ..ResultView::default()
+            });
+        }
+        Ok((lines, json, false)) => {
+            transition(state, RunState::Failed);
+            state.status = "一致性检查发现不符".to_string();
+            state.results = Arc::new(ResultView {
+                lines,
+                json,
+                ..ResultView::default()
+            });
+        }
+        Err(err) => {
+            transition(state, RunState::Failed);
+            state.status = "一致性比对失败".to_string();
+            state.error = Some(err);
+        }
+    });
+}
+
+fn send_summary() {
+    let summary = {
+        let state = ui_state()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if state.run_state.is_running() {
+            return;
+        }
+        state.results.summary.clone()
+    };
+    let Some(summary) = summary else {
+        return;
+    };
+    let status = match wearable::send_summary(&summary) {
+        Ok(sent) => format!("结果已发送到 {} 台设备", sent),
+        Err(err) => format!("发送到手表失败: {}", err),
+    };
+    update_state_and_render(|state| state.status = status);
+}
+
+// 宿主可能直接给出输入内容，也可能包成 JSON 字符串或 {"value": ...}
+fn input_value(payload: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(payload) {
+        Ok(serde_json::Value::String(text)) => text,
+        Ok(serde_json::Value::Number(number)) => number.to_string(),
+        Ok(serde_json::Value::Object(object)) => match object.get("value") {
+            Some(serde_json::Value::String(text)) => text.clone(),
+            Some(other) => other.to_string(),
+            None => String::new(),
+        },
+        _ => payload.to_string(),
     }
 }
 
+// 清空输入即取消该项阈值；无法解析或非正数时保持原值
+fn set_threshold(event: &str, payload: &str) {
+    let (id, is_p50) = match (
+        event.strip_prefix(THRESHOLD_P50_EVENT_PREFIX),
+        event.strip_prefix(THRESHOLD_SCORE_EVENT_PREFIX),
+    ) {
+        (Some(id), _) => (id, true),
+        (_, Some(id)) => (id, false),
+        _ => return,
+    };
+    let Some(case) = benchmark::find_case(id) else {
+        return;
+    };
+    let text = input_value(payload);
+    let value = match text.trim() {
+        "" => None,
+        trimmed => match trimmed.parse::<f64>() {
+            Ok(value) if value.is_finite() && value > 0.0 => Some(value),
+            _ => {
+                tracing::warn!("ignoring invalid threshold for {}: {:?}", case.id, trimmed);
+                return;
+            }
+        },
+    };
+    update_config(|config| {
+        let thresholds = &mut config.thresholds;
+        let index = match thresholds.iter().position(|t| t.id == case.id) {
+            Some(index) => index,
+            None => {
+                thresholds.push(CaseThreshold {
+                    id: case.id,
+                    ..CaseThreshold::default()
+                });
+                thresholds.len() - 1
+            }
+        };
+        if is_p50 {
+            thresholds[index].max_p50_ms = value;
+        } else {
+            thresholds[index].min_score = value;
+        }
+        if !thresholds[index].is_set() {
+            thresholds.remove(index);
+        }
+    });
+}
+
+// 输入为空时取消覆盖，超出范围时夹到边界，等于默认 n 时视为不覆盖
+fn set_n_override(id: &str, payload: &str) {
+    let Some(case) = benchmark::find_case(id) else {
+        return;
+    };
+    let text = input_value(payload);
+    let n = match text.trim() {
+        "" => None,
+        trimmed => match trimmed.parse::<u64>() {
+            Ok(n) => {
+                let (min, max) = benchmark::n_override_range(case);
+                Some(n.clamp(min, max)).filter(|&n| n != case.n)
+            }
+            Err(_) => {
+                tracing::warn!("ignoring invalid n for {}: {:?}", case.id, trimmed);
+                return;
+            }
+        },
+    };
+    update_config(|config| {
+        config.n_overrides.retain(|&(other, _)| other != case.id);
+        if let Some(n) = n {
+            config.n_overrides.push((case.id, n));
+        }
+    });
+}
+
+fn toggle_case_info(id: &str) {
+    let Some(case) = benchmark::find_case(id) else {
+        return;
+    };
+    update_state_and_render(|state| {
+        let expanded = &mut state.expanded_cases;
+        match expanded.iter().position(|&other| other == case.id) {
+            Some(i) => {
+                expanded.remove(i);
+            }
+            None => expanded.push(case.id),
+        }
+        save_prefs(state);
+    });
+}
+
+fn toggle_case_selected(id: &str) {
+    let Some(case) = benchmark::find_case(id) else {
+        return;
+    };
+    update_config(|config| {
+        let skipped = &mut config.skip_cases;
+        match skipped.iter().position(|&other| other == case.id) {
+            Some(i) => {
+                skipped.remove(i);
+            }
+            None => skipped.push(case.id),
+        }
+    });
+}
+
+pub fn refresh() {
+    update_state_and_render(|_| {});
+}
+
+fn show_tab(tab: Tab) {
+    update_state_and_render(|state| {
+        state.tab = tab;
+        save_prefs(state);
+    });
+}
+
+pub fn set_locale(locale: Locale) {
+    update_state_and_render(|state| state.locale = locale);
+}
+
+pub fn ui_event_processor(evtype: ui::Event, event: &str, payload: &str) {
+    if matches!(evtype, ui::Event::Change) {
+        match event.strip_prefix(N_OVERRIDE_EVENT_PREFIX) {
+            Some(id) => set_n_override(id, payload),
+            None => set_threshold(event, payload),
+        }
+        return;
+    }
+    if !matches!(evtype, ui::Event::Click) {
+        return;
+    }
+    match event {
+        START_BENCH_EVENT => run_benchmark_with_ui(),
+        TOGGLE_BARRIER_EVENT => toggle_barrier(),
+        TOGGLE_VERIFY_EVENT => update_config(|c| c.verify_digests = !c.verify_digests),
+        TOGGLE_ABORT_EVENT => update_config(|c| c.abort_on_mismatch = !c.abort_on_mismatch),
+        TOGGLE_POLICY_EVENT => toggle_policy(),
+        TOGGLE_COLD_EVENT => update_config(|c| c.cold_start = !c.cold_start),
+        CYCLE_CHUNKS_EVENT => cycle_chunks(),
+        TOGGLE_PROFILE_EVENT => toggle_profile(),
+        TOGGLE_ANONYMIZE_EVENT => update_state_and_render(|state| {
+            state.anonymize = !state.anonymize;
+            save_prefs(state);
+        }),
+        TOGGLE_ACCESSIBLE_EVENT => update_state_and_render(|state| {
+            state.accessible = !state.accessible;
+            save_prefs(state);
+        }),
+        TOGGLE_TIMER_CORRECTION_EVENT => {
+            update_config(|c| c.timer_correction = !c.timer_correction)
+        }
+        TOGGLE_RETRY_EVENT => update_config(|c| c.retry_noisy = !c.retry_noisy),
+        TOGGLE_TRACE_EVENT => update_config(|c| c.export_trace = !c.export_trace),
+        TOGGLE_BATCH_EVENT => update_config(|c| c.batch_chunks = !c.batch_chunks),
+        TOGGLE_TIME_UNIT_EVENT => update_config(|c| c.time_unit = c.time_unit.toggled()),
+        CYCLE_TIME_DECIMALS_EVENT => cycle_time_decimals(),
+        CYCLE_MINUTES_EVENT => cycle_minutes(),
+        TOGGLE_HEARTBEAT_LOG_EVENT => update_config(|c| c.heartbeat_log = !c.heartbeat_log),
+        CYCLE_UTC_OFFSET_EVENT => cycle_utc_offset(),
+        CYCLE_AB_VARIANT_EVENT => update_config(|c| c.ab_variant = c.ab_variant.next()),
+        TOGGLE_STREAM_EVENT => update_config(|c| c.stream_progress = !c.stream_progress),
+        CANCEL_BENCH_EVENT => cancel_benchmark(),
+        PAUSE_BENCH_EVENT => toggle_pause(),
+        SEND_SUMMARY_EVENT => send_summary(),
+        SET_BASELINE_EVENT => set_baseline(),
+        IMPORT_BASELINE_EVENT => import_baseline(),
+        EXPORT_BUNDLE_EVENT => export_bundle(),
+        IMPORT_BUNDLE_EVENT => import_bundle_file(),
+        GENERATE_FIXTURE_EVENT => run_conformance(false),
+        CHECK_FIXTURE_EVENT => run_conformance(true),
+        PROBE_EVENTS_EVENT => {
+            event_probe::start();
+            refresh();
+        }
+        SHOW_BENCH_TAB_EVENT => show_tab(Tab::Bench),
+        SHOW_ABOUT_TAB_EVENT => show_tab(Tab::About),
+        QUEUE_ADD_EVENT => enqueue(current_profile()),
+        QUEUE_ADD_QUICK_EVENT => enqueue("quick"),
+        QUEUE_RUN_EVENT => start_queue(),
+        _ => {
+            if let Some(id) = event.strip_prefix(CASE_INFO_EVENT_PREFIX) {
+                toggle_case_info(id);
+            } else if let Some(id) = event.strip_prefix(CASE_SELECT_EVENT_PREFIX) {
+                toggle_case_selected(id);
+            } else if let Some(index) = event
+                .strip_prefix(HISTORY_SELECT_EVENT_PREFIX)
+                .and_then(|suffix| suffix.parse::<usize>().ok())
+            {
+                toggle_history(index);
+            } else {
+                queue_action(event);
+            }
+        }
+    }
+}
+
+fn current_profile() -> &'static str {
+    ui_state()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .config
+        .profile
+        .as_str()
+}
+
+// 无障碍开关决定的字号与配色，build_main_ui 按快照生成后逐层传给各构建函数
+#[derive(Clone, Copy)]
+struct Theme {
+    accessible: bool,
+}
+
+impl Theme {
+    fn font(self, size: u32) -> u32 {
+        if self.accessible {
+            (size as f32 * LARGE_TEXT_SCALE).round() as u32
+        } else {
+            size
+        }
+    }
+
+    // 高对比配色：灰色文字与边框一律改为黑色，彩色加深到在白底上对比度不低于 7:1
+    fn fg(self, color: &'static str) -> &'static str {
+        if !self.accessible {
+            return color;
+        }
+        match color {
+            "#ffffff" => "#ffffff",
+            "#14b86a" => "#00582a",
+            "#e5484d" => "#9b0016",
+            "#d48806" => "#6b4000",
+            _ => "#000000",
+        }
+    }
+
+    // 彩色按钮与进度条加深以衬托白字，浅灰底改为中灰，与白色页面拉开差距
+    fn bg(self, color: &'static str) -> &'static str {
+        if !self.accessible {
+            return color;
+        }
+        match color {
+            "#14b86a" => "#00582a",
+            "#e5484d" => "#9b0016",
+            "#3b82f6" => "#1e3a8a",
+            "#9c9c9c" | "#9e9e9e" => "#4d4d4d",
+            "#eeeeee" | "#e6e6e6" => "#c8c8c8",
+            "#fdecec" => "#ffffff",
+            other => other,
+        }
+    }
+}
+
+fn build_toggle(theme: Theme, label: &str, event_id: &str, running: bool) -> ui::Element {
+    let toggle = ui::Element::new(ui::ElementType::Button, Some(label))
+        .bg(theme.bg("#eeeeee"))
+        .text_color(theme.fg("#333333"))
+        .size(theme.font(14))
+        .padding(8)
+        .radius(6)
+        .margin_right(8);
+
+    if running {
+        toggle.disabled()
+    } else {
+        toggle.on(ui::Event::Click, event_id)
+    }
+}
+
+fn build_tab_button(theme: Theme, label: &str, event_id: &str, selected: bool) -> ui::Element {
+    let tab = ui::Element::new(ui::ElementType::Button, Some(label))
+        .size(theme.font(14))
+        .padding(8)
+        .radius(6)
+        .margin_right(8);
+
+    if selected {
+        tab.bg(theme.bg("#14b86a")).text_color(theme.fg("#ffffff"))
+    } else {
+        tab.bg(theme.bg("#eeeeee"))
+            .text_color(theme.fg("#333333"))
+            .on(ui::Event::Click, event_id)
+    }
+}
+
+fn severity_color(theme: Theme, severity: Severity) -> Option<&'static str> {
+    match severity {
+        Severity::Info => None,
+        Severity::Muted => Some(theme.fg("#777777")),
+        Severity::Success => Some(theme.fg("#14b86a")),
+        Severity::Warning => Some(theme.fg("#d48806")),
+        Severity::Error => Some(theme.fg("#e5484d")),
+    }
+}
+
+// 灰色段为已完成的热身，绿色段为已完成的正式测量
+fn build_progress_bar(theme: Theme, split: &ProgressSplit) -> ui::Element {
+    let segment = |fraction: f64, color: &'static str| {
+        let width = (fraction.clamp(0.0, 1.0) * f64::from(PROGRESS_BAR_WIDTH)).round() as u32;
+        ui::Element::new(ui::ElementType::Div, None)
+            .width(width)
+            .height(PROGRESS_BAR_HEIGHT)
+            .bg(theme.bg(color))
+    };
+    ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Row)
+        .width(PROGRESS_BAR_WIDTH)
+        .height(PROGRESS_BAR_HEIGHT)
+        .bg(theme.bg("#e6e6e6"))
+        .radius(4)
+        .margin_bottom(6)
+        .child(segment(split.warmup, "#9e9e9e"))
+        .child(segment(split.measure, "#14b86a"))
+}
+
+// 每根竖条是一个点，高度按窗口内最大值归一；持续变矮说明在降频
+fn build_sparkline(theme: Theme, points: &VecDeque<f64>) -> ui::Element {
+    let max = points.iter().copied().fold(0.0, f64::max);
+    points.iter().fold(
+        ui::Element::new(ui::ElementType::Div, None)
+            .flex()
+            .flex_direction(ui::FlexDirection::Row)
+            .align_end()
+            .height(SPARKLINE_HEIGHT)
+            .margin_bottom(8),
+        |row, &rate| {
+            let fraction = if max > 0.0 { rate / max } else { 0.0 };
+            let height = (fraction * f64::from(SPARKLINE_HEIGHT)).round().max(1.0) as u32;
+            row.child(
+                ui::Element::new(ui::ElementType::Div, None)
+                    .width(SPARKLINE_BAR_WIDTH)
+                    .height(height)
+                    .bg(theme.bg("#14b86a"))
+                    .margin_right(1),
+            )
+        },
+    )
+}
+
+fn build_line_item(theme: Theme, line: &LineItem) -> ui::Element {
+    let content = line.to_string();
+    let el = ui::Element::new(ui::ElementType::P, Some(content.as_str()))
+        .size(theme.font(14))
+        .margin_bottom(4);
+    match severity_color(theme, line.severity) {
+        Some(color) => el.text_color(color),
+        None => el,
+    }
+}
+
+fn build_threshold_input(
+    theme: Theme,
+    label: &str,
+    value: Option<f64>,
+    event_id: &str,
+    running: bool,
+) -> ui::Element {
+    let text = value.map(|v| v.to_string());
+    let input = ui::Element::new(ui::ElementType::Input, text.as_deref())
+        .size(theme.font(12))
+        .padding(4)
+        .radius(6)
+        .border(1, theme.fg("#cccccc"))
+        .margin_right(8);
+    let input = if running {
+        input.disabled()
+    } else {
+        input.on(ui::Event::Change, event_id)
+    };
+    ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Row)
+        .margin_bottom(4)
+        .child(
+            ui::Element::new(ui::ElementType::P, Some(label))
+                .size(theme.font(12))
+                .text_color(theme.fg("#666666"))
+                .margin_right(8),
+        )
+        .child(input)
+}
+
+fn build_history(theme: Theme, history: &[(String, bool)], running: bool) -> ui::Element {
+    let title = format!(
+        "历史结果 {}/{}（勾选后与本次结果对比，或导出为合集）",
+        history.len(),
+        result_history::MAX_RESULTS
+    );
+    let mut list = ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Column)
+        .align_start()
+        .margin_bottom(12)
+        .child(
+            ui::Element::new(ui::ElementType::P, Some(title.as_str()))
+                .size(theme.font(14))
+                .margin_bottom(4),
+        );
+
+    // 新的在前
+    for (index, (label, selected)) in history.iter().enumerate().rev() {
+        let label = format!("{} {}", if *selected { "☑" } else { "☐" }, label);
+        let event = format!("{}{}", HISTORY_SELECT_EVENT_PREFIX, index);
+        list = list
+            .child(build_toggle(theme, label.as_str(), event.as_str(), running).margin_bottom(4));
+    }
+
+    let none_selected = !history.iter().any(|(_, selected)| *selected);
+    let actions = ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Row)
+        .child(build_toggle(
+            theme,
+            "导出所选为合集",
+            EXPORT_BUNDLE_EVENT,
+            running || none_selected,
+        ))
+        .child(build_toggle(
+            theme,
+            "导入结果合集",
+            IMPORT_BUNDLE_EVENT,
+            running,
+        ));
+    list.child(actions)
+}
+
+// 每项可上移或移除，开跑后按钮都不可用
+fn build_queue(
+    theme: Theme,
+    queue: &[&'static str],
+    profile: Profile,
+    running: bool,
+) -> ui::Element {
+    let title = format!(
+        "运行队列 {}/{}（两次之间冷却 {} 秒）",
+        queue.len(),
+        run_queue::MAX_QUEUE,
+        run_queue::COOLDOWN_SECS
+    );
+    let mut list = ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Column)
+        .align_start()
+        .margin_bottom(12)
+        .child(
+            ui::Element::new(ui::ElementType::P, Some(title.as_str()))
+                .size(theme.font(14))
+                .margin_bottom(4),
+        );
+
+    for (index, entry) in queue.iter().enumerate() {
+        let label = format!("{}. {}", index + 1, entry);
+        let up_event = format!("{}{}", QUEUE_UP_EVENT_PREFIX, index);
+        let remove_event = format!("{}{}", QUEUE_REMOVE_EVENT_PREFIX, index);
+        let row = ui::Element::new(ui::ElementType::Div, None)
+            .flex()
+            .flex_direction(ui::FlexDirection::Row)
+            .margin_bottom(4)
+            .child(
+                ui::Element::new(ui::ElementType::P, Some(label.as_str()))
+                    .size(theme.font(14))
+                    .margin_right(8),
+            )
+            .child(build_toggle(
+                theme,
+                "上移",
+                up_event.as_str(),
+                running || index == 0,
+            ))
+            .child(build_toggle(theme, "移除", remove_event.as_str(), running));
+        list = list.child(row);
+    }
+
+    let full = queue.len() >= run_queue::MAX_QUEUE;
+    let add_label = format!("加入队列: {}", profile.as_str());
+    let actions = ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Row)
+        .child(build_toggle(
+            theme,
+            add_label.as_str(),
+            QUEUE_ADD_EVENT,
+            running || full,
+        ))
+        .child(build_toggle(
+            theme,
+            "加入队列: quick",
+            QUEUE_ADD_QUICK_EVENT,
+            running || full,
+        ))
+        .child(build_toggle(
+            theme,
+            "运行队列",
+            QUEUE_RUN_EVENT,
+            running || queue.is_empty(),
+        ));
+    list.child(actions)
+}
+
+// 展开后显示说明与验收阈值，留空表示不设定
+fn build_case_list(
+    theme: Theme,
+    expanded_cases: &[&'static str],
+    config: &BenchmarkConfig,
+    running: bool,
+) -> ui::Element {
+    let mut list = ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Column)
+        .align_start()
+        .margin_top(12)
+        .child(
+            ui::Element::new(ui::ElementType::P, Some("测试项"))
+                .size(theme.font(16))
+                .margin_bottom(8),
+        );
+
+    for case in benchmark::all_cases() {
+        let event_id = format!("{}{}", CASE_INFO_EVENT_PREFIX, case.id);
+        let select_event = format!("{}{}", CASE_SELECT_EVENT_PREFIX, case.id);
+        let expanded = expanded_cases.contains(&case.id);
+        let info_label = if expanded { "收起" } else { "说明" };
+        let selected = !config.skip_cases.contains(&case.id);
+        let case_label = format!("{} {}", if selected { "☑" } else { "☐" }, case.id);
+        let select = build_toggle(theme, case_label.as_str(), select_event.as_str(), running);
+        let row = ui::Element::new(ui::ElementType::Div, None)
+            .flex()
+            .flex_direction(ui::FlexDirection::Row)
+            .margin_bottom(4)
+            .child(select)
+            .child(
+                ui::Element::new(ui::ElementType::Button, Some(info_label))
+                    .bg(theme.bg("#eeeeee"))
+                    .text_color(theme.fg("#333333"))
+                    .size(theme.font(12))
+                    .padding(4)
+                    .radius(6)
+                    .on(ui::Event::Click, event_id.as_str()),
+            );
+        list = list.child(row);
+        if expanded {
+            list = list.child(
+                ui::Element::new(ui::ElementType::P, Some(case.description))
+                    .size(theme.font(12))
+                    .text_color(theme.fg("#666666"))
+                    .margin_bottom(8),
+            );
+            let (min, max) = benchmark::n_override_range(case);
+            let n_label = format!("迭代数 n（默认 {}，{}–{}）", case.n, min, max);
+            let n_event = format!("{}{}", N_OVERRIDE_EVENT_PREFIX, case.id);
+            list = list.child(build_threshold_input(
+                theme,
+                n_label.as_str(),
+                config.n_override(case.id).map(|n| n as f64),
+                n_event.as_str(),
+                running,
+            ));
+            let threshold = config.threshold(case.id);
+            let p50_event = format!("{}{}", THRESHOLD_P50_EVENT_PREFIX, case.id);
+            list = list.child(build_threshold_input(
+                theme,
+                "p50 上限 (ms)",
+                threshold.and_then(|t| t.max_p50_ms),
+                p50_event.as_str(),
+                running,
+            ));
+            if let Some(work) = &case.work {
+                let label = format!("最低吞吐 ({})", work.unit);
+                let score_event = format!("{}{}", THRESHOLD_SCORE_EVENT_PREFIX, case.id);
+                list = list.child(build_threshold_input(
+                    theme,
+                    label.as_str(),
+                    threshold.and_then(|t| t.min_score),
+                    score_event.as_str(),
+                    running,
+                ));
+            }
+        }
+    }
+    list
+}
+
+fn on_off(value: bool) -> &'static str {
+    if value { "开" } else { "关" }
+}
+
+fn build_error_banner(theme: Theme, title: &str, message: &str) -> ui::Element {
+    ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Column)
+        .align_start()
+        .width_full()
+        .bg(theme.bg("#fdecec"))
+        .border(1, theme.fg("#e5484d"))
+        .radius(8)
+        .padding(12)
+        .margin_bottom(12)
+        .child(
+            ui::Element::new(ui::ElementType::P, Some(title))
+                .size(theme.font(16))
+                .text_color(theme.fg("#e5484d"))
+                .margin_bottom(4),
+        )
+        .child(
+            ui::Element::new(ui::ElementType::P, Some(message))
+                .size(theme.font(14))
+                .text_color(theme.fg("#e5484d")),
+        )
+}
+
 fn build_main_ui(snapshot: &UiSnapshot) -> ui::Element {
-    let title_text = "AstroBox Benchmark";
+    let theme = snapshot.theme();
+    let title = ui::Element::new(ui::ElementType::P, Some("AstroBox Benchmark"))
+        .size(theme.font(28))
+        .margin_bottom(8);
+
+    let accessible_label = format!("无障碍显示: {}", on_off(snapshot.accessible));
+    let tabs = ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Row)
+        .margin_bottom(12)
+        .child(build_tab_button(
+            theme,
+            "测试",
+            SHOW_BENCH_TAB_EVENT,
+            snapshot.tab == Tab::Bench,
+        ))
+        .child(build_tab_button(
+            theme,
+            "关于",
+            SHOW_ABOUT_TAB_EVENT,
+            snapshot.tab == Tab::About,
+        ))
+        .child(build_toggle(
+            theme,
+            accessible_label.as_str(),
+            TOGGLE_ACCESSIBLE_EVENT,
+            false,
+        ));
+
+    let root = ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Column)
+        .width_full()
+        .justify_start()
+        .align_start()
+        .padding(16)
+        .child(title)
+        .child(tabs);
+
+    match snapshot.tab {
+        Tab::Bench => build_bench_tab(root, snapshot),
+        Tab::About => build_about_tab(root, snapshot),
+    }
+}
+
+fn build_about_tab(root: ui::Element, snapshot: &UiSnapshot) -> ui::Element {
+    let theme = snapshot.theme();
+    let mut lines = text::about_lines();
+    lines.extend(text::capability_lines());
+    lines.extend(text::lifetime_lines(
+        &lifetime::load(),
+        snapshot.utc_offset_min,
+    ));
+    lines
+        .iter()
+        .fold(root, |root, line| root.child(build_line_item(theme, line)))
+}
+
+fn build_bench_tab(root: ui::Element, snapshot: &UiSnapshot) -> ui::Element {
+    let theme = snapshot.theme();
     let subtitle_text = format!(
-        "固定参数: --seed {} --n1 {} --n2 {} --warmup {} --repeats {}",
+        "固定参数: seed {} · n1 {} · n2 {} · 预热 {} · 重复 {}",
         benchmark::BENCH_SEED,
-        benchmark::BENCH_N1,
-        benchmark::BENCH_N2,
-        benchmark::BENCH_WARMUP,
-        benchmark::BENCH_REPEATS
+        format::count(snapshot.config.n1(), snapshot.locale),
+        format::count(snapshot.config.n2(), snapshot.locale),
+        snapshot.config.warmup,
+        snapshot.config.repeats
     );
 
-    let title = ui::Element::new(ui::ElementType::P, Some(title_text))
-        .size(28)
-        .margin_bottom(4);
-
     let subtitle = ui::Element::new(ui::ElementType::P, Some(subtitle_text.as_str()))
-        .size(14)
-        .text_color("#666666")
+        .size(theme.font(14))
+        .text_color(theme.fg("#666666"))
         .margin_bottom(12);
 
-    let button_label = if snapshot.running { "测试中..." } else { "开始测试" };
+    let running = snapshot.run_state.is_running();
+    let button_label = match snapshot.run_state {
+        RunState::Preparing => "准备中...",
+        RunState::Warmup => "预热中...",
+        RunState::Measuring => "测试中...",
+        RunState::Cooldown => "汇总结果...",
+        RunState::Paused => "已暂停",
+        RunState::Cancelling => "正在取消...",
+        RunState::Finished | RunState::Failed => "重新测试",
+        RunState::Idle => "开始测试",
+    };
     let mut start_button = ui::Element::new(ui::ElementType::Button, Some(button_label))
-        .bg(if snapshot.running { "#9c9c9c" } else { "#14b86a" })
-        .text_color("#ffffff")
+        .bg(theme.bg(if running { "#9c9c9c" } else { "#14b86a" }))
+        .text_color(theme.fg("#ffffff"))
+        .size(theme.font(16))
         .padding(12)
         .radius(8)
         .margin_bottom(12);
 
-    if snapshot.running {
+    if running {
         start_button = start_button.disabled();
     } else {
         start_button = start_button.on(ui::Event::Click, START_BENCH_EVENT);
     }
 
-    let percent = if snapshot.progress_total > 0 {
-        (snapshot.progress_done as f64 / snapshot.progress_total as f64) * 100.0
+    let barrier_label = format!("屏障: {}", snapshot.config.barrier.as_str());
+    let verify_label = format!("校验 digest: {}", on_off(snapshot.config.verify_digests));
+    let abort_label = format!("不符即中止: {}", on_off(snapshot.config.abort_on_mismatch));
+    let policy_label = format!(
+        "失败策略: {}",
+        match snapshot.config.failure_policy {
+            FailurePolicy::ContinueOthers => "继续其他",
+            FailurePolicy::AbortSuite => "中止全部",
+        }
+    );
+    let settings = ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Row)
+        .margin_bottom(12)
+        .child(build_toggle(
+            theme,
+            barrier_label.as_str(),
+            TOGGLE_BARRIER_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            verify_label.as_str(),
+            TOGGLE_VERIFY_EVENT,
+            running,
+        ));
+    let cold_label = format!("冷启动对比: {}", on_off(snapshot.config.cold_start));
+    let settings = settings
+        .child(build_toggle(
+            theme,
+            policy_label.as_str(),
+            TOGGLE_POLICY_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            cold_label.as_str(),
+            TOGGLE_COLD_EVENT,
+            running,
+        ));
+    let settings = if snapshot.config.verify_digests {
+        settings.child(build_toggle(
+            theme,
+            abort_label.as_str(),
+            TOGGLE_ABORT_EVENT,
+            running,
+        ))
+    } else {
+        settings
+    };
+    let chunks_label = format!("进度块数: {}", snapshot.config.chunks);
+    let batch_label = format!("合并块回调: {}", on_off(snapshot.config.batch_chunks));
+    let stream_label = format!("进度事件流: {}", on_off(snapshot.config.stream_progress));
+    let correction_label = format!("计时校正: {}", on_off(snapshot.config.timer_correction));
+    let retry_label = format!("噪声重跑: {}", on_off(snapshot.config.retry_noisy));
+    let trace_label = format!("导出 trace: {}", on_off(snapshot.config.export_trace));
+    let time_unit_label = format!("JSON 耗时单位: {}", snapshot.config.time_unit.as_str());
+    let decimals_label = format!("JSON 耗时小数位: {}", snapshot.config.time_decimals);
+    let offset_label = format!("时区: {}", timestamp::offset_label(snapshot.utc_offset_min));
+    let settings = settings
+        .child(build_toggle(
+            theme,
+            chunks_label.as_str(),
+            CYCLE_CHUNKS_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            batch_label.as_str(),
+            TOGGLE_BATCH_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            stream_label.as_str(),
+            TOGGLE_STREAM_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            correction_label.as_str(),
+            TOGGLE_TIMER_CORRECTION_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            retry_label.as_str(),
+            TOGGLE_RETRY_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            trace_label.as_str(),
+            TOGGLE_TRACE_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            time_unit_label.as_str(),
+            TOGGLE_TIME_UNIT_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            decimals_label.as_str(),
+            CYCLE_TIME_DECIMALS_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            offset_label.as_str(),
+            CYCLE_UTC_OFFSET_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            "测量事件延迟",
+            PROBE_EVENTS_EVENT,
+            running,
+        ));
+    let profile_label = format!(
+        "测试模式: {}",
+        match snapshot.config.profile {
+            Profile::Standard => "标准",
+            Profile::Sustained => "持续负载",
+            Profile::Ab => "A/B 对比",
+        }
+    );
+    let settings = settings.child(build_toggle(
+        theme,
+        profile_label.as_str(),
+        TOGGLE_PROFILE_EVENT,
+        running,
+    ));
+    let settings = if snapshot.config.profile == Profile::Sustained {
+        let minutes_label = format!("持续时长: {} 分钟", snapshot.config.sustained_minutes);
+        let heartbeat_label = format!("心跳写入文件: {}", on_off(snapshot.config.heartbeat_log));
+        settings
+            .child(build_toggle(
+                theme,
+                minutes_label.as_str(),
+                CYCLE_MINUTES_EVENT,
+                running,
+            ))
+            .child(build_toggle(
+                theme,
+                heartbeat_label.as_str(),
+                TOGGLE_HEARTBEAT_LOG_EVENT,
+                running,
+            ))
+    } else if snapshot.config.profile == Profile::Ab {
+        let variant = snapshot.config.ab_variant;
+        let b = variant.apply(&snapshot.config);
+        let variant_label = format!(
+            "B 改动: {} → {}",
+            variant.describe(&snapshot.config),
+            variant.describe(&b)
+        );
+        settings.child(build_toggle(
+            theme,
+            variant_label.as_str(),
+            CYCLE_AB_VARIANT_EVENT,
+            running,
+        ))
     } else {
-        0.0
+        settings
     };
-    let progress_text = format!(
+
+    let mut progress_text = format!(
         "进度: {}/{} ({:.1}%)",
-        snapshot.progress_done, snapshot.progress_total, percent
+        snapshot.progress_done,
+        snapshot.progress_total,
+        snapshot.progress_split.percent()
     );
+    if let Some(remaining) = snapshot.progress_split.remaining_ms.filter(|_| running) {
+        progress_text.push_str(&format!(
+            " · 预计剩余 {:.0} 秒",
+            (remaining / 1000.0).ceil()
+        ));
+    }
     let progress = ui::Element::new(ui::ElementType::P, Some(progress_text.as_str()))
-        .size(16)
-        .margin_bottom(6);
+        .size(theme.font(16))
+        .margin_bottom(4);
+
+    let live_rate = snapshot.live_rate.map(|rate| {
+        let text = format!("当前速度: {}", format::rate(rate, snapshot.locale));
+        ui::Element::new(ui::ElementType::P, Some(text.as_str()))
+            .size(theme.font(14))
+            .text_color(theme.fg("#14b86a"))
+            .margin_bottom(6)
+    });
 
     let status = ui::Element::new(ui::ElementType::P, Some(snapshot.status.as_str()))
-        .size(14)
-        .text_color("#444444")
+        .size(theme.font(14))
+        .text_color(theme.fg("#444444"))
         .margin_bottom(12);
 
     let mut results_container = ui::Element::new(ui::ElementType::Div, None)
@@ -226,44 +2273,166 @@ fn build_main_ui(snapshot: &UiSnapshot) -> ui::Element {
         .flex_direction(ui::FlexDirection::Column)
         .align_start();
 
-    if snapshot.result_lines.is_empty() && snapshot.result_json.is_none() {
+    if snapshot.results.lines.is_empty() && snapshot.results.json.is_none() {
         results_container = results_container.child(
             ui::Element::new(ui::ElementType::P, Some("结果会在这里显示。"))
-                .size(14)
-                .text_color("#777777"),
+                .size(theme.font(14))
+                .text_color(theme.fg("#777777")),
         );
     } else {
-        for line in &snapshot.result_lines {
-            results_container = results_container.child(
-                ui::Element::new(ui::ElementType::P, Some(line.as_str()))
-                    .size(14)
-                    .margin_bottom(4),
-            );
+        for line in &snapshot.results.lines {
+            results_container = results_container.child(build_line_item(theme, line));
+        }
+        for line in &snapshot.results.compare_lines {
+            results_container = results_container.child(build_line_item(theme, line));
         }
-        if let Some(json) = &snapshot.result_json {
+        if let Some(json) = &snapshot.results.json {
+            let json = if snapshot.anonymize {
+                share::anonymize_text(json)
+            } else {
+                json.clone()
+            };
             let json_label = ui::Element::new(ui::ElementType::P, Some("JSON:"))
-                .size(14)
+                .size(theme.font(14))
                 .margin_top(8);
+            let anonymize_label = format!("分享时匿名化: {}", on_off(snapshot.anonymize));
+            results_container = results_container.child(build_toggle(
+                theme,
+                anonymize_label.as_str(),
+                TOGGLE_ANONYMIZE_EVENT,
+                running,
+            ));
             let json_text = ui::Element::new(ui::ElementType::P, Some(json.as_str()))
-                .size(12)
-                .text_color("#555555");
+                .size(theme.font(12))
+                .text_color(theme.fg("#555555"));
             results_container = results_container.child(json_label).child(json_text);
         }
     }
 
-    ui::Element::new(ui::ElementType::Div, None)
+    let cancel_button = running.then(|| {
+        ui::Element::new(ui::ElementType::Button, Some("取消"))
+            .bg(theme.bg("#e5484d"))
+            .text_color(theme.fg("#ffffff"))
+            .size(theme.font(16))
+            .padding(12)
+            .radius(8)
+            .margin_bottom(12)
+            .on(ui::Event::Click, CANCEL_BENCH_EVENT)
+    });
+
+    // 只有预热与测量阶段能暂停
+    let pause_label = match snapshot.run_state {
+        RunState::Warmup | RunState::Measuring => Some("暂停"),
+        RunState::Paused => Some("继续"),
+        _ => None,
+    };
+    let pause_button = pause_label.map(|label| {
+        ui::Element::new(ui::ElementType::Button, Some(label))
+            .bg(theme.bg("#f59e0b"))
+            .text_color(theme.fg("#ffffff"))
+            .size(theme.font(16))
+            .padding(12)
+            .radius(8)
+            .margin_bottom(12)
+            .on(ui::Event::Click, PAUSE_BENCH_EVENT)
+    });
+
+    let send_button = (!running && snapshot.results.summary.is_some()).then(|| {
+        ui::Element::new(ui::ElementType::Button, Some("发送到手表"))
+            .bg(theme.bg("#3b82f6"))
+            .text_color(theme.fg("#ffffff"))
+            .size(theme.font(16))
+            .padding(12)
+            .radius(8)
+            .margin_bottom(12)
+            .on(ui::Event::Click, SEND_SUMMARY_EVENT)
+    });
+
+    let conformance = ui::Element::new(ui::ElementType::Div, None)
         .flex()
-        .flex_direction(ui::FlexDirection::Column)
-        .width_full()
-        .justify_start()
-        .align_start()
-        .padding(16)
-        .child(title)
-        .child(subtitle)
-        .child(start_button)
+        .flex_direction(ui::FlexDirection::Row)
+        .margin_bottom(12)
+        .child(build_toggle(
+            theme,
+            "生成一致性基准",
+            GENERATE_FIXTURE_EVENT,
+            running,
+        ))
+        .child(build_toggle(
+            theme,
+            "导入基准并比对",
+            CHECK_FIXTURE_EVENT,
+            running,
+        ));
+
+    let compare_row = ui::Element::new(ui::ElementType::Div, None)
+        .flex()
+        .flex_direction(ui::FlexDirection::Row)
+        .margin_bottom(12)
+        .child(build_toggle(
+            theme,
+            "设为对比基准",
+            SET_BASELINE_EVENT,
+            running || snapshot.results.summary.is_none(),
+        ))
+        .child(build_toggle(
+            theme,
+            "导入对比基准",
+            IMPORT_BASELINE_EVENT,
+            running,
+        ));
+
+    let mut root = root.child(subtitle);
+    if let Some(line) = text::debug_build_line() {
+        root = root.child(build_line_item(theme, &line));
+    }
+    root = root
+        .child(settings)
+        .child(conformance)
+        .child(compare_row)
+        .child(build_history(theme, &snapshot.history, running))
+        .child(build_queue(
+            theme,
+            &snapshot.queue,
+            snapshot.config.profile,
+            running,
+        ))
+        .child(start_button);
+
+    if let Some(pause_button) = pause_button {
+        root = root.child(pause_button);
+    }
+    if let Some(cancel_button) = cancel_button {
+        root = root.child(cancel_button);
+    }
+    if let Some(send_button) = send_button {
+        root = root.child(send_button);
+    }
+    root = root
         .child(progress)
-        .child(status)
-        .child(results_container)
+        .child(build_progress_bar(theme, &snapshot.progress_split));
+
+    if let Some(live_rate) = live_rate {
+        root = root.child(live_rate);
+    }
+
+    root = root.child(status);
+    if snapshot.sparkline.len() > 1 {
+        root = root.child(build_sparkline(theme, &snapshot.sparkline));
+    }
+    if let Some(line) = text::event_probe_line() {
+        root = root.child(build_line_item(theme, &line));
+    }
+    if let Some(error) = &snapshot.error {
+        root = root.child(build_error_banner(theme, snapshot.status.as_str(), error));
+    }
+
+    root.child(results_container).child(build_case_list(
+        theme,
+        &snapshot.expanded_cases,
+        &snapshot.config,
+        running,
+    ))
 }
 
 pub fn render_main_ui(element_id: &str) {
@@ -272,6 +2441,22 @@ pub fn render_main_ui(element_id: &str) {
             .lock()
             .unwrap_or_else(|poisoned| poisoned.into_inner());
         state.root_element_id = Some(element_id.to_string());
+        if !state.prefs_restored && !state.run_state.is_running() {
+            restore_prefs(&mut state);
+            state.baseline_json = storage::read(BASELINE_FILE)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok());
+            state.history = result_history::load();
+            refresh_history_rows(&mut state);
+            if let Some(record) = checkpoint::take_interrupted() {
+                state.status = "上次运行异常中断".to_string();
+                state.results = Arc::new(ResultView {
+                    lines: text::interrupted_lines(&record, state.utc_offset_min),
+                    ..ResultView::default()
+                });
+            }
+            state.prefs_restored = true;
+        }
         let root = state.root_element_id.clone();
         let snapshot = snapshot_from(&state);
         (root, snapshot)
@@ -280,4 +2465,49 @@ pub fn render_main_ui(element_id: &str) {
     if let Some(root) = root {
         psys_host::ui::render(&root, build_main_ui(&snapshot));
     }
+    env::mark_first_render();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(failures: &[&str], halted: bool, skipped: usize) -> SuiteOutcome {
+        SuiteOutcome {
+            failures: failures.iter().map(|f| f.to_string()).collect(),
+            halted,
+            skipped,
+        }
+    }
+
+    #[test]
+    fn planned_skip_finishes() {
+        assert!(matches!(
+            finish_state(&outcome(&[], false, 1)),
+            (RunState::Finished, "测试完成（部分用例已跳过）")
+        ));
+        assert!(matches!(
+            finish_state(&outcome(&[], false, 0)),
+            (RunState::Finished, "测试完成")
+        ));
+    }
+
+    #[test]
+    fn failures_end_as_failed() {
+        assert!(matches!(
+            finish_state(&outcome(&["T1_INT32_MIX 出错"], false, 1)),
+            (RunState::Failed, "测试完成（部分用例失败）")
+        ));
+        assert!(matches!(
+            finish_state(&outcome(&["T1_INT32_MIX 已取消"], true, 0)),
+            (RunState::Failed, "测试已中止")
+        ));
+    }
+
+    #[test]
+    fn paused_counts_as_running() {
+        assert!(RunState::Paused.is_running());
+        assert!(!RunState::Finished.is_running());
+        assert!(!RunState::Failed.is_running());
+    }
 }
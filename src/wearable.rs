@@ -0,0 +1,67 @@
+// 把结果摘要推送到已连接的手表/手环。经 interconnect 发给表端快应用，
+// 表端需要安装包名为 WATCH_PKG 的配套应用才能显示
+use crate::astrobox::psys_host::{device, interconnect};
+use crate::benchmark::{self, BenchmarkResult, CaseOutcome};
+
+pub const WATCH_PKG: &str = "com.astralsight.benchmark";
+
+// 总分：完成用例吞吐值的几何平均。各用例单位不同（Mops/s、MB/s 等），只宜在同一版本的结果之间比较；
+// 没有吞吐指标的用例不计入，一个都没有时为 None
+fn score(result: &BenchmarkResult) -> Option<f64> {
+    let values: Vec<f64> = result
+        .cases
+        .iter()
+        .filter(|case| case.outcome == CaseOutcome::Ok)
+        .filter_map(|case| case.throughput.as_ref())
+        .map(|throughput| throughput.value)
+        .filter(|value| value.is_finite() && *value > 0.0)
+        .collect();
+    if values.is_empty() {
+        return None;
+    }
+    let log_mean = values.iter().map(|value| value.ln()).sum::<f64>() / values.len() as f64;
+    Some(log_mean.exp())
+}
+
+// 表端屏幕和链路都有限，只带总分、每个用例的 p50 与最终 digest
+pub fn summary_json(result: &BenchmarkResult) -> String {
+    let count = |outcome: CaseOutcome| result.cases.iter().filter(|c| c.outcome == outcome).count();
+    let p50_ms: serde_json::Map<String, serde_json::Value> = result
+        .cases
+        .iter()
+        .filter(|case| case.outcome == CaseOutcome::Ok)
+        .map(|case| (case.id.to_string(), benchmark::ms3(case.stats.p50).into()))
+        .collect();
+    serde_json::json!({
+        "ok": count(CaseOutcome::Ok),
+        "failed": count(CaseOutcome::Failed),
+        "skipped": count(CaseOutcome::Skipped) + count(CaseOutcome::Cancelled),
+        "score": score(result).map(benchmark::ms3),
+        "p50_ms": p50_ms,
+        "suite_digest": benchmark::hex(result.suite_digest),
+        "final_digest": result.final_digest.map(benchmark::hex),
+    })
+    .to_string()
+}
+
+// 发给所有已连接设备，返回成功送达的设备数
+pub fn send_summary(summary: &str) -> Result<usize, String> {
+    wit_bindgen::block_on(async {
+        let devices = device::get_connected_device_list().await;
+        if devices.is_empty() {
+            return Err("没有已连接的设备".to_string());
+        }
+        let mut sent = 0;
+        for info in &devices {
+            match interconnect::send_qaic_message(&info.addr, WATCH_PKG, summary).await {
+                Ok(()) => sent += 1,
+                Err(()) => tracing::warn!("failed to send summary to {}", info.name),
+            }
+        }
+        if sent == 0 {
+            Err("发送失败".to_string())
+        } else {
+            Ok(sent)
+        }
+    })
+}